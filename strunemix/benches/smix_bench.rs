@@ -1,4 +1,5 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::borrow::Cow;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use strunemix::*;
 
 #[derive(Strunemix)]
@@ -32,7 +33,7 @@ struct LongForm {
 }
 
 impl StrunemixParsableData<'_, LongFormAttrData> for LongFormAttrName {
-    fn add_data(&self, data: &'_ str) -> Result<LongFormAttrData, StrunemixParseError> {
+    fn add_data(&self, data: Cow<'_, str>) -> Result<LongFormAttrData, StrunemixParseError> {
         match self {
             LongFormAttrName::A => Ok(LongFormAttrData::A(data.parse().unwrap())),
             LongFormAttrName::B => Ok(LongFormAttrData::B(data.parse().unwrap())),
@@ -116,13 +117,118 @@ fn filling() -> LongForm {
     form.set_data_str("y", "").unwrap();
     form.set_data_str("z", "").unwrap();
 
-    let res = LongForm::from_form(form).unwrap();
+    LongForm::from_form(form).unwrap()
+}
+
+/// A struct with many fields, where finding a field by its name is the most costly.
+#[derive(Strunemix, Default)]
+struct WideForm {
+    f00: u32,
+    f01: u32,
+    f02: u32,
+    f03: u32,
+    f04: u32,
+    f05: u32,
+    f06: u32,
+    f07: u32,
+    f08: u32,
+    f09: u32,
+    f10: u32,
+    f11: u32,
+    f12: u32,
+    f13: u32,
+    f14: u32,
+    f15: u32,
+    f16: u32,
+    f17: u32,
+    f18: u32,
+    f19: u32,
+    f20: u32,
+    f21: u32,
+    f22: u32,
+    f23: u32,
+    f24: u32,
+    f25: u32,
+    f26: u32,
+    f27: u32,
+    f28: u32,
+    f29: u32,
+    f30: u32,
+    f31: u32,
+    f32: u32,
+    f33: u32,
+    f34: u32,
+    f35: u32,
+    f36: u32,
+    f37: u32,
+    f38: u32,
+    f39: u32,
+    f40: u32,
+    f41: u32,
+    f42: u32,
+    f43: u32,
+    f44: u32,
+    f45: u32,
+    f46: u32,
+    f47: u32,
+    f48: u32,
+    f49: u32,
+    f50: u32,
+    f51: u32,
+    f52: u32,
+    f53: u32,
+    f54: u32,
+    f55: u32,
+    f56: u32,
+    f57: u32,
+    f58: u32,
+    f59: u32,
+}
+
+fn wide_get_data(form: &StrunemixForm<WideFormAttrName, WideFormAttrData, 60>) -> u32 {
+    WideForm::as_name_array()
+    .into_iter()
+    .filter_map(|name| match form.get_data(name) {
+        Ok(Some(data)) => Some(data.name().index() as u32),
+        _ => None,
+    })
+    .sum()
+}
 
-    res
+fn wide_set_data(form: &mut StrunemixForm<WideFormAttrName, WideFormAttrData, 60>) {
+    for data in WideForm::default().to_data_array() {
+        form.set_data(data.name(), data).unwrap();
+    }
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("longform", |b| b.iter(|| filling()));
+    c.bench_function("longform", |b| b.iter(filling));
+
+    c.bench_function("longform from_form", |b| b.iter_batched(
+        || filling().to_form::<()>(),
+        |form| LongForm::from_form(form).unwrap(),
+        BatchSize::SmallInput,
+    ));
+
+    c.bench_function("longform from_attr_data_array", |b| b.iter_batched(
+        || {
+            let mut datas = filling().to_data_array();
+            datas.reverse();
+            datas
+        },
+        |datas| LongForm::from_attr_data_array(datas).unwrap(),
+        BatchSize::SmallInput,
+    ));
+
+    let mut form = WideForm::default().to_form::<()>();
+    c.bench_function("wideform get_data", |b| b.iter(|| wide_get_data(black_box(&form))));
+    c.bench_function("wideform set_data", |b| b.iter(|| wide_set_data(black_box(&mut form))));
+
+    c.bench_function("wideform from_form", |b| b.iter_batched(
+        || WideForm::default().to_form::<()>(),
+        |form| WideForm::from_form(form).unwrap(),
+        BatchSize::SmallInput,
+    ));
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,75 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+/// Length of a value, checked by the `#[strunemix(length(min = ..., max = ...))]` field attribute.
+/// 
+/// The length of a string is its number of characters, the length of a collection is its number of items.
+pub trait StrunemixLength {
+    /// Get the length of the value.
+    fn length(&self) -> usize;
+}
+
+impl StrunemixLength for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl StrunemixLength for String {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T> StrunemixLength for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> StrunemixLength for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: StrunemixLength + ?Sized> StrunemixLength for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Regular expression checked by the `#[strunemix(pattern = "...")]` field attribute, compiled on its first use.
+/// 
+/// An invalid regular expression never matches, the derive macro refuses them at compile time.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// static PATTERN: StrunemixPattern = StrunemixPattern::new("^[a-z]+$");
+/// 
+/// assert!(PATTERN.is_match("john"));
+/// assert!(!PATTERN.is_match("John"));
+/// ```
+#[cfg(feature = "regex")]
+pub struct StrunemixPattern {
+    pattern: &'static str,
+    regex: OnceLock<Option<regex::Regex>>,
+}
+
+#[cfg(feature = "regex")]
+impl StrunemixPattern {
+    /// Create a pattern from a regular expression.
+    pub const fn new(pattern: &'static str) -> Self {
+        Self { pattern, regex: OnceLock::new() }
+    }
+
+    /// Check that the whole value or a part of it matches the regular expression.
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex.get_or_init(|| regex::Regex::new(self.pattern).ok())
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(value))
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 use crate::*;
 
 /// Trait implemented automatically on enums data generated by strunemix.
@@ -25,4 +27,166 @@ where
     {
         T::from(self)
     }
-}
\ No newline at end of file
+
+    /// Check the data against the constraints declared on its field.
+    /// 
+    /// The constraints are declared with `#[strunemix(range(...), length(...), pattern = "...", one_of(...))]` on the field.
+    /// They are also checked when data is set in a [StrunemixForm], and when a struct is built from a form.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(range(min = 0, max = 150))]
+    ///   age: i32,
+    ///   #[strunemix(length(max = 8))]
+    ///   pseudo: String,
+    ///   #[strunemix(one_of("light", "dark"))]
+    ///   theme: Option<String>,
+    /// }
+    /// 
+    /// assert!(PersonAttrData::Age(42).check().is_ok());
+    /// assert!(PersonAttrData::Theme(None).check().is_ok());
+    /// 
+    /// let error = PersonAttrData::Age(-1).check().unwrap_err();
+    /// assert_eq!(error.field, "age");
+    /// assert_eq!(error.constraint, StrunemixConstraint::Range { min: Some("0"), max: Some("150") });
+    /// 
+    /// let error = PersonAttrData::Theme(Some("blue".to_string())).check().unwrap_err();
+    /// assert_eq!(error.to_string(), r#"The field 'theme' does not satisfy one_of("light", "dark")"#);
+    /// ```
+    fn check(&self) -> Result<(), StrunemixConstraintError> {
+        Ok(())
+    }
+
+    /// Get the default data of a field, if it has one.
+    /// 
+    /// It is declared with `#[strunemix(default)]` on the field to use the [`Default`] implementation of its type,
+    /// or with `#[strunemix(default = path)]` to call a function returning it.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   #[strunemix(default)]
+    ///   age: i32,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrData::default_data(&PersonAttrName::Age), Some(PersonAttrData::Age(0)));
+    /// assert_eq!(PersonAttrData::default_data(&PersonAttrName::Pseudo), None);
+    /// ```
+    fn default_data(_name: &T) -> Option<Self> {
+        None
+    }
+
+    /// Get a copy of the data, kept by a form as the initial data of a field given mutably,
+    /// so that the field can still be reset and is only dirty if the data is changed.
+    /// 
+    /// It is derived as a clone when [`Clone`] is derived on the enum of data of a struct without generics, with `#[strunemix_derive_data(...)]` or `#[strunemix_derive(...)]`.
+    /// Otherwise a field given mutably is considered changed in place.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Person {
+    ///   age: i32,
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// assert_eq!(PersonAttrData::Age(42).snapshot(), Some(PersonAttrData::Age(42)));
+    /// 
+    /// let mut form = Person {age: 42}.to_form::<()>();
+    /// if let Some(PersonAttrData::Age(age)) = form.get_data_mut("age")? {
+    ///     *age += 1;
+    /// }
+    /// 
+    /// form.reset("age")?;
+    /// assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(42)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn snapshot(&self) -> Option<Self> {
+        None
+    }
+}
+
+/// Trait that allow the conversion from each of the inner types of an enum of datas back to a string.
+/// 
+/// It is the inverse of [StrunemixParsableData], and enables the use of [`StrunemixForm::get_data_str`] on a [StrunemixForm].\
+/// Add the `#[strunemix_display]` attribute on the struct to derive it from the [`Display`](std::fmt::Display) implementation of each field,
+/// or implement it by hand.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_display]
+/// struct Person {
+///    age: i32,
+///    name: String,
+/// }
+/// 
+/// assert_eq!(PersonAttrData::Age(42).to_data_string(), "42");
+/// assert_eq!(PersonAttrData::Name("John".to_string()).to_data_string(), "John");
+/// ```
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// struct Person {
+///    age: i32,
+///    name: Option<String>,
+/// }
+/// 
+/// impl StrunemixDisplayableData<PersonAttrName> for PersonAttrData {
+///   fn to_data_string(&self) -> String {
+///     match self {
+///       PersonAttrData::Age(age) => age.to_string(),
+///       PersonAttrData::Name(name) => name.clone().unwrap_or_default(),
+///     }
+///   }
+/// }
+/// 
+/// assert_eq!(PersonAttrData::Name(None).to_data_string(), "");
+/// ```
+pub trait StrunemixDisplayableData<T>: StrunemixData<T>
+where 
+    T: StrunemixName,
+{
+    /// Convert the inner value of the enum data into a string.
+    fn to_data_string(&self) -> String;
+}
+
+/// Trait that allow the conversion of each of the fields of a struct into a string, without consuming it.
+/// 
+/// It enables the use of [`StrunemixTrait::to_logfmt`] on the struct.\
+/// It is derived along with [StrunemixDisplayableData] by the `#[strunemix_display]` attribute, from the [`Display`](std::fmt::Display) implementation of each field.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_display]
+/// struct Person {
+///    age: i32,
+///    name: String,
+/// }
+/// 
+/// let person = Person {age: 42, name: "John".to_string()};
+/// assert_eq!(person.to_data_strings(), ["42", "John"]);
+/// ```
+pub trait StrunemixDisplayableFields<const N: usize> {
+    /// Convert the value of each field into a string, in the order of the fields.
+    fn to_data_strings(&self) -> [String; N];
+}
@@ -0,0 +1,547 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::*;
+
+/// A value of a field of a [DynForm].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    List(Vec<DynValue>),
+}
+
+/// The type of the values of a field of a [DynForm].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynKind {
+    Bool,
+    Int,
+    Float,
+    Str,
+    /// A list of values of the same type.
+    List(Box<DynKind>),
+    /// A value of the type, or [`DynValue::Null`].
+    Nullable(Box<DynKind>),
+}
+
+impl DynKind {
+    /// Check that the value is of this type.
+    pub fn accepts(&self, value: &DynValue) -> bool {
+        match (self, value) {
+            (DynKind::Nullable(_), DynValue::Null) => true,
+            (DynKind::Nullable(kind), value) => kind.accepts(value),
+            (DynKind::List(kind), DynValue::List(values)) => values.iter().all(|value| kind.accepts(value)),
+            (DynKind::Bool, DynValue::Bool(_))
+            | (DynKind::Int, DynValue::Int(_))
+            | (DynKind::Float, DynValue::Float(_))
+            | (DynKind::Str, DynValue::Str(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Parse a string into a value of this type, the same way the derived [StrunemixParsableData] does:
+    /// an empty string or `null` is [`DynValue::Null`] for a nullable type, and the items of a list are separated by commas.
+    pub fn parse(&self, data: &str) -> Result<DynValue, StrunemixParseError> {
+        Ok(match self {
+            DynKind::Nullable(_) if data.is_empty() || data == "null" => DynValue::Null,
+            DynKind::Nullable(kind) => kind.parse(data)?,
+            DynKind::List(_) if data.is_empty() => DynValue::List(Vec::new()),
+            DynKind::List(kind) => DynValue::List(data.split(',').map(|data| kind.parse(data)).collect::<Result<_, _>>()?),
+            DynKind::Bool => DynValue::Bool(data.parse()?),
+            DynKind::Int => DynValue::Int(data.parse()?),
+            DynKind::Float => DynValue::Float(data.parse()?),
+            DynKind::Str => DynValue::Str(String::from(data)),
+        })
+    }
+}
+
+impl Display for DynKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DynKind::Bool => write!(f, "bool"),
+            DynKind::Int => write!(f, "int"),
+            DynKind::Float => write!(f, "float"),
+            DynKind::Str => write!(f, "string"),
+            DynKind::List(kind) => write!(f, "list of {kind}"),
+            DynKind::Nullable(kind) => write!(f, "{kind} or null"),
+        }
+    }
+}
+
+/// The names and types of the fields of a [DynForm], declared at runtime.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// let schema = DynSchema::new()
+///     .field("pseudo", DynKind::Str)
+///     .field("tags", DynKind::List(Box::new(DynKind::Str)));
+///
+/// assert_eq!(schema.kind("tags"), Some(&DynKind::List(Box::new(DynKind::Str))));
+/// assert_eq!(schema.names().collect::<Vec<_>>(), ["pseudo", "tags"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynSchema {
+    fields: Vec<(String, DynKind)>,
+}
+
+impl DynSchema {
+    /// Create a schema without any field.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field to the schema, or change its type if it is already in it.
+    pub fn field(mut self, name: impl Into<String>, kind: DynKind) -> Self {
+        let name = name.into();
+        match self.position(&name) {
+            Some(index) => self.fields[index].1 = kind,
+            None => self.fields.push((name, kind)),
+        }
+        self
+    }
+
+    /// The schema of the fields of a strunemixed struct.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_dyn]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: Option<u8>,
+    /// }
+    ///
+    /// let schema = DynSchema::new()
+    ///     .field("pseudo", DynKind::Str)
+    ///     .field("age", DynKind::Nullable(Box::new(DynKind::Int)));
+    ///
+    /// assert_eq!(DynSchema::of::<PersonAttrName, PersonAttrData>(), schema);
+    /// ```
+    pub fn of<T, U>() -> Self
+    where
+        T: StrunemixName,
+        U: StrunemixDynData<T>
+    {
+        let fields = (0..).map_while(T::from_index)
+        .map(|name| (String::from(name.get_str()), U::dyn_kind(&name)))
+        .collect();
+
+        Self { fields }
+    }
+
+    /// Get the type of a field.
+    pub fn kind(&self, name: &str) -> Option<&DynKind> {
+        self.fields.iter().find(|(field, _)| field == name).map(|(_, kind)| kind)
+    }
+
+    /// The names of the fields, in the order they were declared.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// The number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Check that the schema has no field.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|(field, _)| field == name)
+    }
+
+    /// The first field that is not in both schemas with the same type, whatever the order of the fields.
+    fn mismatch(&self, other: &DynSchema) -> Option<String> {
+        let differs = |(name, kind): &&(String, DynKind), schema: &DynSchema| schema.kind(name) != Some(kind);
+
+        self.fields.iter().find(|field| differs(field, other))
+        .or_else(|| other.fields.iter().find(|field| differs(field, self)))
+        .map(|(name, _)| name.clone())
+    }
+}
+
+/// A form whose fields are only known at runtime, checked against a [DynSchema].
+/// It mirrors the API of [StrunemixForm], and converts into one when its schema is the one of a struct with `#[strunemix_dyn]`.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// # fn main() -> Result<(), StrunemixError> {
+/// let schema = DynSchema::new()
+///     .field("pseudo", DynKind::Str)
+///     .field("age", DynKind::Int)
+///     .field("phone", DynKind::Nullable(Box::new(DynKind::Str)));
+///
+/// let mut form = DynForm::new(schema);
+/// form.set_data_str("pseudo", "John")?;
+/// form.set_data("age", DynValue::Int(42))?;
+/// assert!(!form.is_complete());
+/// assert_eq!(form.missing_fields(), ["phone"]);
+///
+/// form.set_data_str("phone", "null")?;
+/// assert!(form.is_complete());
+/// assert_eq!(form.get_data("phone")?, Some(&DynValue::Null));
+///
+/// assert!(form.set_data("age", DynValue::Str("old".to_string())).is_err());
+/// assert!(form.set_data_str("age", "old").is_err());
+/// assert!(form.get_data("height").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynForm {
+    schema: DynSchema,
+    values: Vec<Option<DynValue>>,
+}
+
+impl DynForm {
+    /// Create a form without data for the fields of the schema.
+    pub fn new(schema: DynSchema) -> Self {
+        let values = schema.fields.iter().map(|_| None).collect();
+        Self { schema, values }
+    }
+
+    /// The schema the data of the form is checked against.
+    pub fn schema(&self) -> &DynSchema {
+        &self.schema
+    }
+
+    /// Get the data of a field by its name.
+    pub fn get_data(&self, name: &str) -> Result<Option<&DynValue>, StrunemixError> {
+        let index = self.index_of(name)?;
+        Ok(self.values[index].as_ref())
+    }
+
+    /// Set the data of a field by its name, if it is of the type of the field.
+    pub fn set_data(&mut self, name: &str, value: DynValue) -> Result<(), StrunemixError> {
+        let index = self.index_of(name)?;
+        let kind = &self.schema.fields[index].1;
+
+        if !kind.accepts(&value) {
+            return Err(StrunemixDynError::WrongKind { field: String::from(name), expected: kind.clone(), value }.into());
+        }
+
+        self.values[index] = Some(value);
+        Ok(())
+    }
+
+    /// Set the data of a field by its name, parsed from a string with [`DynKind::parse`].
+    pub fn set_data_str(&mut self, name: &str, data: &str) -> Result<(), StrunemixError> {
+        let index = self.index_of(name)?;
+        let value = self.schema.fields[index].1.parse(data)
+        .map_err(|source| StrunemixDynError::Parse { field: String::from(name), input: String::from(data), source })?;
+
+        self.values[index] = Some(value);
+        Ok(())
+    }
+
+    /// Remove the data of a field by its name.
+    pub fn remove_data(&mut self, name: &str) -> Result<(), StrunemixError> {
+        let index = self.index_of(name)?;
+        self.values[index] = None;
+        Ok(())
+    }
+
+    /// Check that every field has data.
+    pub fn is_complete(&self) -> bool {
+        self.values.iter().all(Option::is_some)
+    }
+
+    /// The names of the fields that have no data, in the order of the schema.
+    pub fn missing_fields(&self) -> Vec<&str> {
+        self.schema.names()
+        .zip(self.values.iter())
+        .filter(|(_, value)| value.is_none())
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// Convert a typed form into a dynamic one, with the schema of its struct.
+    /// The data of the fields is kept as is, the info is dropped.
+    pub fn from_form<T, U, const N: usize, A>(form: StrunemixForm<T, U, N, A>) -> Self
+    where
+        T: StrunemixName + PartialEq,
+        U: StrunemixDynData<T>
+    {
+        let schema = DynSchema::of::<T, U>();
+        let values = form.into_data_slots().into_iter().map(|data| data.map(U::into_dyn)).collect();
+
+        Self { schema, values }
+    }
+
+    /// Build a typed form from this one, if its schema is the one of the struct, whatever the order of the fields.
+    /// The data of the fields is copied as the initial data of the typed form, so this form is left as it is on error.
+    /// The data of each field is checked against the constraints declared on it.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// #[strunemix_dyn]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: u8,
+    /// }
+    ///
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let schema = DynSchema::new()
+    ///     .field("age", DynKind::Int)
+    ///     .field("pseudo", DynKind::Str);
+    ///
+    /// let mut form = DynForm::new(schema);
+    /// form.set_data_str("pseudo", "John")?;
+    /// form.set_data_str("age", "42")?;
+    ///
+    /// let form = form.to_form::<PersonAttrName, PersonAttrData, 2, ()>()?;
+    /// assert_eq!(Person::from_form(form)?, Person { pseudo: "John".to_string(), age: 42 });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_form<T, U, const N: usize, A>(&self) -> Result<StrunemixForm<T, U, N, A>, StrunemixError>
+    where
+        T: StrunemixName + PartialEq,
+        U: StrunemixDynData<T>,
+        A: Default
+    {
+        if let Some(field) = self.schema.mismatch(&DynSchema::of::<T, U>()) {
+            return Err(StrunemixDynError::SchemaMismatch(field).into());
+        }
+
+        let names: [Option<T>; N] = core::array::from_fn(T::from_index);
+        let mut fields = Vec::with_capacity(N);
+
+        for name in names.iter().flatten() {
+            let field = String::from(name.get_str());
+            let index = self.schema.position(&field).ok_or_else(|| StrunemixDynError::SchemaMismatch(field.clone()))?;
+
+            let data = match &self.values[index] {
+                Some(value) => {
+                    let data = U::from_dyn(name, value)
+                        .ok_or_else(|| StrunemixDynError::WrongKind { field, expected: U::dyn_kind(name), value: value.clone() })?;
+                    data.check()?;
+                    Some(data)
+                },
+                None => None,
+            };
+            fields.push(Field::new(data, A::default()));
+        }
+
+        let fields = array_init::from_iter(fields).ok_or(StrunemixError::IncompleteForm)?;
+        Ok(StrunemixForm::from_fields(names, fields))
+    }
+
+    fn index_of(&self, name: &str) -> Result<usize, StrunemixDynError> {
+        self.schema.position(name).ok_or_else(|| StrunemixDynError::UnknownField(String::from(name)))
+    }
+}
+
+/// Trait implemented automatically on the enum of data of structs with `#[strunemix_dyn]`, to convert it from and into a [DynValue].
+pub trait StrunemixDynData<T: StrunemixName>: StrunemixData<T> {
+    /// The type of the values of the field.
+    fn dyn_kind(name: &T) -> DynKind;
+
+    /// Convert a value into the data of the field, if it is of its type and fits in it.
+    fn from_dyn(name: &T, value: &DynValue) -> Option<Self>;
+
+    /// Convert the data into a value, without losing anything.
+    fn into_dyn(self) -> DynValue;
+}
+
+/// Types of fields that can be converted from and into a [DynValue] without losing anything, needed by `#[strunemix_dyn]`.
+///
+/// The exceptions are `u64` and `usize` values above `i64::MAX`, which are saturated, and `f32` fields, which take the nearest value of a [DynValue::Float].
+///
+/// `Option<Option<V>>` is not supported, because `Some(None)` and `None` would both be [DynValue::Null]:
+///
+/// ```rust,compile_fail
+/// use strunemix::*;
+///
+/// let value = Some(None::<u8>).into_dyn();
+/// ```
+pub trait StrunemixDynValue: Sized {
+    /// Whether [DynValue::Null] is one of the values of the type.
+    #[doc(hidden)]
+    const NULLABLE: bool = false;
+
+    /// The type of the values.
+    fn dyn_kind() -> DynKind;
+
+    /// Convert a value, if it is of the type and fits in it.
+    fn from_dyn(value: &DynValue) -> Option<Self>;
+
+    /// Convert into a value.
+    fn into_dyn(self) -> DynValue;
+}
+
+macro_rules! dyn_int {
+    ($($ty:ty),*) => {$(
+        impl StrunemixDynValue for $ty {
+            fn dyn_kind() -> DynKind {
+                DynKind::Int
+            }
+
+            fn from_dyn(value: &DynValue) -> Option<Self> {
+                match value {
+                    DynValue::Int(value) => Self::try_from(*value).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_dyn(self) -> DynValue {
+                DynValue::Int(i64::from(self))
+            }
+        }
+    )*};
+}
+
+dyn_int!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! dyn_wide_int {
+    ($($ty:ty),*) => {$(
+        impl StrunemixDynValue for $ty {
+            fn dyn_kind() -> DynKind {
+                DynKind::Int
+            }
+
+            fn from_dyn(value: &DynValue) -> Option<Self> {
+                match value {
+                    DynValue::Int(value) => Self::try_from(*value).ok(),
+                    _ => None,
+                }
+            }
+
+            fn into_dyn(self) -> DynValue {
+                DynValue::Int(i64::try_from(self).unwrap_or(i64::MAX))
+            }
+        }
+    )*};
+}
+
+dyn_wide_int!(u64, usize, isize);
+
+impl StrunemixDynValue for f64 {
+    fn dyn_kind() -> DynKind {
+        DynKind::Float
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        match value {
+            DynValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        DynValue::Float(self)
+    }
+}
+
+impl StrunemixDynValue for f32 {
+    fn dyn_kind() -> DynKind {
+        DynKind::Float
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        match value {
+            DynValue::Float(value) => {
+                let narrowed = *value as f32;
+                (narrowed.is_finite() || !value.is_finite()).then_some(narrowed)
+            },
+            _ => None,
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        DynValue::Float(f64::from(self))
+    }
+}
+
+impl StrunemixDynValue for bool {
+    fn dyn_kind() -> DynKind {
+        DynKind::Bool
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        match value {
+            DynValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        DynValue::Bool(self)
+    }
+}
+
+impl StrunemixDynValue for String {
+    fn dyn_kind() -> DynKind {
+        DynKind::Str
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        match value {
+            DynValue::Str(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        DynValue::Str(self)
+    }
+}
+
+struct NotNested<V>(core::marker::PhantomData<V>);
+
+impl<V: StrunemixDynValue> NotNested<V> {
+    const CHECK: () = assert!(!V::NULLABLE, "Nested options can't be converted to dynamic values");
+}
+
+impl<V: StrunemixDynValue> StrunemixDynValue for Option<V> {
+    const NULLABLE: bool = true;
+
+    fn dyn_kind() -> DynKind {
+        let () = NotNested::<V>::CHECK;
+        DynKind::Nullable(Box::new(V::dyn_kind()))
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        let () = NotNested::<V>::CHECK;
+        match value {
+            DynValue::Null => Some(None),
+            value => V::from_dyn(value).map(Some),
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        let () = NotNested::<V>::CHECK;
+        self.map_or(DynValue::Null, V::into_dyn)
+    }
+}
+
+impl<V: StrunemixDynValue> StrunemixDynValue for Vec<V> {
+    fn dyn_kind() -> DynKind {
+        DynKind::List(Box::new(V::dyn_kind()))
+    }
+
+    fn from_dyn(value: &DynValue) -> Option<Self> {
+        match value {
+            DynValue::List(values) => values.iter().map(V::from_dyn).collect(),
+            _ => None,
+        }
+    }
+
+    fn into_dyn(self) -> DynValue {
+        DynValue::List(self.into_iter().map(V::into_dyn).collect())
+    }
+}
@@ -1,40 +1,204 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use thiserror::Error;
 
+use crate::{DynKind, DynValue, StrunemixData, StrunemixForm, StrunemixName, ValidationReport};
+use crate::lookup::DidYouMean;
+
 /// Strunemix errors in conversions
 #[derive(Error, Debug, PartialEq)]
 pub enum StrunemixFromError {
     #[error("Invalid input data, wrong order of data")]
     WrongOrder,
-    #[error("Invalid input data, some data attributes are missing while others are present more than once")]
-    AppearedMoreThanOnce,
+    #[error("Invalid input data, missing: [{}], duplicated: [{}]", .missing.join(", "), .duplicated.join(", "))]
+    MissingOrDuplicated { missing: Vec<&'static str>, duplicated: Vec<&'static str> },
     #[error("The string '{0}' is not a valid for the enum {1}")]
     NotAnEnumName(String, String),
+    /// Like [`StrunemixFromError::NotAnEnumName`], with the names close enough to the string to be a typo.
+    #[error("The string '{name}' is not a valid for the enum {enum_name}{suggestions}", suggestions = DidYouMean(.suggestions))]
+    NotAnEnumNameDidYouMean { name: String, enum_name: String, suggestions: Vec<&'static str> },
+}
+
+impl StrunemixFromError {
+    /// Check that each field appears exactly once, from the number of times each of them appears.
+    #[doc(hidden)]
+    pub fn check_counts(names: &[&'static str], counts: &[usize]) -> Result<(), Self> {
+        if counts.iter().all(|count| *count == 1) {
+            return Ok(());
+        }
+
+        let names_with = |keep: fn(usize) -> bool| names.iter()
+            .zip(counts)
+            .filter(|(_, count)| keep(**count))
+            .map(|(name, _)| *name)
+            .collect();
+
+        Err(StrunemixFromError::MissingOrDuplicated {
+            missing: names_with(|count| count == 0),
+            duplicated: names_with(|count| count > 1),
+        })
+    }
 }
 
 /// General Strunemix error
 #[derive(Error, Debug)]
 pub enum StrunemixError {
-    #[error("Parse error: {0}")]
+    #[error("Parse error")]
     ParseError(#[from] StrunemixParseError),
+    #[error("Field parse error")]
+    FieldParseError(#[from] StrunemixFieldParseError),
     #[error("Incomplete form")]
     IncompleteForm,
-    #[error("Conversion error: {0}")]
+    #[error("Conversion error")]
     ConversionError(#[from] StrunemixFromError),
+    #[error("Invalid form: {0}")]
+    Invalid(ValidationReport),
+    #[error("Constraint error")]
+    Constraint(#[from] StrunemixConstraintError),
+    #[error("The initial data of the field was changed in place and can't be restored")]
+    InitialDataLost,
+    #[error("The field '{0}' is not in the form")]
+    MissingField(&'static str),
+    #[error("The info of the field '{info}' can't be set on the field '{field}'")]
+    WrongInfo { field: &'static str, info: &'static str },
+    #[error("Dynamic form error")]
+    DynError(#[from] StrunemixDynError),
+    #[cfg(feature = "registry")]
+    #[error("The type '{0}' is not registered")]
+    UnknownType(String),
+    #[cfg(feature = "registry")]
+    #[error("The type '{type_name}' is registered more than once, as {}", .type_paths.join(", "))]
+    AmbiguousType { type_name: String, type_paths: Vec<&'static str> },
+}
+
+impl StrunemixError {
+    /// Get the name of the field the error is about, if it is about a single field.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_parse]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let mut form = Person::empty_form::<()>();
+    /// let error = form.set_data_str("age", "old").unwrap_err();
+    /// 
+    /// assert_eq!(error.field(), Some("age"));
+    /// assert_eq!(error.input(), Some("old"));
+    /// ```
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            StrunemixError::FieldParseError(error) => Some(error.field),
+            StrunemixError::Constraint(error) => Some(error.field),
+            StrunemixError::MissingField(field) => Some(field),
+            StrunemixError::WrongInfo { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Get the string that failed to be parsed into the data of a field, if the error comes from parsing one.
+    pub fn input(&self) -> Option<&str> {
+        match self {
+            StrunemixError::FieldParseError(error) => Some(&error.input),
+            StrunemixError::DynError(StrunemixDynError::Parse { input, .. }) => Some(input),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when a struct can't be built from a form, giving the form back with the fields that had no data
+#[derive(Error, Debug)]
+#[error("{error}")]
+pub struct StrunemixFormError<T, U, const N: usize, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    pub form: StrunemixForm<T, U, N, A>,
+    pub missing: Vec<T>,
+    #[source]
+    pub error: StrunemixError,
+}
+
+/// A constraint declared on a field with `#[strunemix(...)]`, the bounds are kept as written in the attribute.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrunemixConstraint {
+    #[error("range(min = {}, max = {})", .min.unwrap_or("_"), .max.unwrap_or("_"))]
+    Range{ min: Option<&'static str>, max: Option<&'static str> },
+    #[error("length(min = {}, max = {})", .min.unwrap_or("_"), .max.unwrap_or("_"))]
+    Length{ min: Option<&'static str>, max: Option<&'static str> },
+    #[error("pattern = \"{0}\"")]
+    Pattern(&'static str),
+    #[error("one_of({})", .0.join(", "))]
+    OneOf(&'static [&'static str]),
+}
+
+/// Error returned when the data of a field does not satisfy one of its declared constraints
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The field '{field}' does not satisfy {constraint}")]
+pub struct StrunemixConstraintError {
+    pub field: &'static str,
+    pub constraint: StrunemixConstraint,
+}
+
+/// Error returned when the data of a field can't be parsed from a string, with the string and the reason it failed
+#[derive(Error, Debug)]
+#[error("The field '{field}' can't be parsed from \"{input}\"")]
+pub struct StrunemixFieldParseError {
+    pub field: &'static str,
+    pub input: String,
+    pub source: StrunemixParseError,
+}
+
+/// Errors of the data of a [DynForm](crate::DynForm), whose fields are only known at runtime
+#[derive(Error, Debug)]
+pub enum StrunemixDynError {
+    #[error("The field '{0}' is not in the schema")]
+    UnknownField(String),
+    #[error("The field '{field}' expects {expected}, got {value:?}")]
+    WrongKind { field: String, expected: DynKind, value: DynValue },
+    #[error("The field '{field}' can't be parsed from \"{input}\"")]
+    Parse { field: String, input: String, source: StrunemixParseError },
+    #[error("The schema doesn't match the one of the struct on the field '{0}'")]
+    SchemaMismatch(String),
 }
 
 /// Strunemix errors in data parsing
 #[derive(Error, Debug)]
 pub enum StrunemixParseError{
-    #[error("Invalid data: {0}")]
-    Other(Box<dyn std::error::Error>),
-    #[error("Invalid Integer: {0}")]
-    ParseIntError(#[from] std::num::ParseIntError),
-    #[error("Invalid Float: {0}")]
-    ParseFloatError(#[from] std::num::ParseFloatError), 
-    #[error("Invalid Bool: {0}")]
-    ParseBoolError(#[from] std::str::ParseBoolError),
-    #[error("Invalid Char: {0}")]
-    ParseCharError(#[from] std::char::ParseCharError),
-    #[error("Invalid String: {0}")]
-    ParseError(#[from] std::string::ParseError),
-}
\ No newline at end of file
+    #[error("Invalid data")]
+    Other(#[source] Box<dyn core::error::Error + Send + Sync>),
+    #[error("Invalid Integer")]
+    ParseIntError(#[from] core::num::ParseIntError),
+    #[error("Invalid Float")]
+    ParseFloatError(#[from] core::num::ParseFloatError), 
+    #[error("Invalid Bool")]
+    ParseBoolError(#[from] core::str::ParseBoolError),
+    #[error("Invalid Char")]
+    ParseCharError(#[from] core::char::ParseCharError),
+    #[error("Invalid String")]
+    ParseError(#[from] alloc::string::ParseError),
+    #[error("Invalid logfmt: {0}")]
+    Logfmt(String),
+}
+
+impl StrunemixParseError {
+    /// Wrap any error, or a message, that made the parsing of some data fail.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// let error = StrunemixParseError::other("not a color");
+    /// assert_eq!(error.to_string(), "Invalid data");
+    /// assert_eq!(core::error::Error::source(&error).map(ToString::to_string), Some("not a color".to_string()));
+    /// ```
+    pub fn other(error: impl Into<Box<dyn core::error::Error + Send + Sync>>) -> Self {
+        StrunemixParseError::Other(error.into())
+    }
+}
@@ -1,37 +1,208 @@
-use micromap::Map;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use crate::*;
+use crate::logfmt::LogfmtPairs;
+use crate::history::{Edit, History};
+#[cfg(feature = "observe")]
+use crate::observe::{Change, Observers};
 
+/// The data and the info of a field in a form, with what is needed to know how its data changed.
 #[doc(hidden)]
-pub(crate) type StrunemixMap<T,U,const N: usize,A> = Map<T, (Option<U>, A), N>;
+#[derive(Debug, PartialEq, Clone)]
+pub struct Field<U, A> {
+    pub(crate) data: Option<U>,
+    pub(crate) info: A,
+    pub(crate) touch: Touch<U>,
+}
 
-/// A form that allows to manipulate the struct data and some custom infos.
+/// How the data of a field changed since the form was created.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Touch<U> {
+    /// The data was not changed.
+    Untouched,
+    /// The data was replaced, the initial one is kept.
+    Touched(Option<U>),
+    /// The data was changed in place before being replaced, the initial one is lost.
+    ChangedInPlace,
+}
+
+impl<U> Touch<U> {
+    /// Keep a snapshot of the data about to be given mutably if it can be snapshotted, or else mark it as changed in place,
+    /// unless the field was already touched.
+    pub(crate) fn give_mut<T>(&mut self, data: &U)
+    where
+        T: StrunemixName,
+        U: StrunemixData<T>
+    {
+        if let Touch::Untouched = self {
+            *self = match data.snapshot() {
+                Some(initial) => Touch::Touched(Some(initial)),
+                None => Touch::ChangedInPlace,
+            };
+        }
+    }
+}
+
+/// What a field held before its data was replaced.
 #[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Previous<U> {
+    /// The previous data is the initial data of the field, kept by the field itself.
+    Initial,
+    /// The previous data.
+    Value(Option<U>),
+}
+
+impl<U, A> Field<U, A> {
+    pub(crate) fn new(data: Option<U>, info: A) -> Self {
+        Self { data, info, touch: Touch::Untouched }
+    }
+
+    /// Replace the data, keeping the initial one the first time.
+    pub(crate) fn replace(&mut self, data: Option<U>) -> Previous<U> {
+        let previous = core::mem::replace(&mut self.data, data);
+
+        match self.touch {
+            Touch::Untouched => {
+                self.touch = Touch::Touched(previous);
+                Previous::Initial
+            },
+            _ => Previous::Value(previous),
+        }
+    }
+
+    /// Put back what the field held before a replacement, and return what is needed to redo it.
+    pub(crate) fn restore(&mut self, previous: Previous<U>) -> Previous<U> {
+        match previous {
+            Previous::Value(data) => self.replace(data),
+            Previous::Initial => match core::mem::replace(&mut self.touch, Touch::Untouched) {
+                Touch::Touched(initial) => Previous::Value(core::mem::replace(&mut self.data, initial)),
+                touch => {
+                    self.touch = touch;
+                    Previous::Initial
+                },
+            }
+        }
+    }
+
+    /// The data the field held before a replacement, from what the replacement returned.
+    #[cfg(feature = "observe")]
+    pub(crate) fn previous<'a>(&'a self, previous: &'a Previous<U>) -> Option<&'a U> {
+        match (previous, &self.touch) {
+            (Previous::Value(data), _) => data.as_ref(),
+            (Previous::Initial, Touch::Touched(initial)) => initial.as_ref(),
+            (Previous::Initial, _) => None,
+        }
+    }
+
+    /// Prepare the data to be given mutably, keeping a snapshot of the initial one if the data can be snapshotted,
+    /// or else marking it as changed in place.
+    pub(crate) fn mark_given_mut<T>(&mut self)
+    where
+        T: StrunemixName,
+        U: StrunemixData<T>
+    {
+        if let Some(data) = &self.data {
+            self.touch.give_mut(data);
+        }
+    }
+
+    fn is_touched(&self) -> bool {
+        !matches!(self.touch, Touch::Untouched)
+    }
+
+    fn is_dirty(&self) -> bool
+    where
+        U: PartialEq
+    {
+        match &self.touch {
+            Touch::Untouched => false,
+            Touch::Touched(initial) => initial != &self.data,
+            Touch::ChangedInPlace => true,
+        }
+    }
+}
+
+/// A form that allows to manipulate the struct data and some custom infos.
+#[derive(Debug, Clone)]
 pub struct StrunemixForm<T, U, const N: usize, A=()> 
 where 
     T: StrunemixName + PartialEq,
     U: StrunemixData<T>
 {
-    map: StrunemixMap<T,U,N,A>
+    /// The name of the field at each position, `None` only if the name of a position can't be known,
+    /// which doesn't happen with the derived implementations.
+    pub(crate) names: [Option<T>; N],
+    /// The fields, at the position given by [`StrunemixName::index`].
+    pub(crate) fields: [Field<U, A>; N],
+    pub(crate) history: Option<History<U>>,
+    /// The edits made by the running [`StrunemixForm::transaction`], if any.
+    pub(crate) transaction: Option<Vec<Edit<U>>>,
+    #[cfg(feature = "observe")]
+    pub(crate) observers: Observers<T, U, A>,
 }
 
-impl<T,U,A, const N: usize> From<StrunemixMap<T,U,N,A>> for StrunemixForm<T, U, N, A> 
+/// Two forms are equal when they have the same names, and the same data and info in each field.
+/// How the data changed since the form was created, the history, the running transaction and the observers are not compared.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+/// struct Person {
+///   pseudo: String,
+///   age: i32,
+/// }
+/// 
+/// # fn main() -> Result<(), StrunemixError> {
+/// let mut form = Person::empty_form::<()>();
+/// form.enable_history(10);
+/// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42))?;
+/// 
+/// let mut other = Person::empty_form::<()>();
+/// assert_ne!(form, other);
+/// 
+/// other.set_data(PersonAttrName::Age, PersonAttrData::Age(42))?;
+/// assert_eq!(form, other);
+/// # Ok(())
+/// # }
+/// ```
+impl<T, U, const N: usize, A> PartialEq for StrunemixForm<T, U, N, A>
 where 
     T: StrunemixName + PartialEq,
-    U: StrunemixData<T>
+    U: StrunemixData<T> + PartialEq,
+    A: PartialEq
 {
-    fn from(map: StrunemixMap<T,U,N,A>) -> Self {
-        Self {map}
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names && self.fields.iter()
+        .zip(&other.fields)
+        .all(|(field, other)| field.data == other.data && field.info == other.info)
     }
 }
 
-const ERR_MISSING_KEY: &str = "The key does not exist, unexpected error";
-
 impl<T, U, const N: usize, A> StrunemixForm<T, U, N, A>
 where 
     T: StrunemixName + PartialEq,
     U: StrunemixData<T>
 {
+    /// Create a form from its fields, in the order given by [`StrunemixName::index`].
+    pub(crate) fn from_fields(names: [Option<T>; N], fields: [Field<U, A>; N]) -> Self {
+        Self {
+            names,
+            fields,
+            history: None,
+            transaction: None,
+            #[cfg(feature = "observe")]
+            observers: Observers::default(),
+        }
+    }
+
+    /// The names and fields of the form, in order.
+    pub(crate) fn named_fields(&self) -> impl Iterator<Item = (&T, &Field<U, A>)> {
+        self.names.iter().zip(self.fields.iter())
+        .filter_map(|(name, field)| Some((name.as_ref()?, field)))
+    }
 
     /// Get the data of a field by its name
     /// 
@@ -57,14 +228,39 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn get_data(&self, name: impl QueryNameTrait<T>) -> Result<Option<&U>, StrunemixError>{
         let name = name.to_attrname()?;
-        let found = self.map.get(&name).map(|(data, _)| data)
-        .expect(ERR_MISSING_KEY)
-        .as_ref();
+        let found = self.field(&name)?.data.as_ref();
+
+        Ok(found)
+    }
+
+    /// Get the data of a field by its name, converted into a string.
+    /// Be sure to implement [StrunemixDisplayableData] on the enum of datas, or to derive it with `#[strunemix_display]`, to use this method.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_display]
+    /// struct Foo {
+    ///    bar: i32,
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let foo_form_empty = Foo::empty_form::<()>();
+    /// assert_eq!(foo_form_empty.get_data_str(FooAttrName::Bar)?, None);
+    /// 
+    /// let foo_form = Foo {bar: 42}.to_form::<()>();
+    /// assert_eq!(foo_form.get_data_str("bar")?, Some("42".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_data_str(&self, name: impl QueryNameTrait<T>) -> Result<Option<String>, StrunemixError>
+    where
+        U: StrunemixDisplayableData<T>
+    {
+        let found = self.get_data(name)?.map(|data| data.to_data_string());
 
         Ok(found)
     }
@@ -89,19 +285,18 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn get_info(&self, name: impl QueryNameTrait<T>) -> Result<&A, StrunemixError> {
         let name = name.to_attrname()?;
-        let found = self.map.get(&name).map(|(_, info)| info)
-        .expect(ERR_MISSING_KEY);
+        let found = &self.field(&name)?.info;
 
         Ok(found)
     }
 
     /// Get a mutable reference to the data of a field by its name
     /// 
+    /// The field is touched, and its initial data is kept for [`StrunemixForm::reset`] if [`StrunemixData::snapshot`] gives a copy of it,
+    /// so it is only dirty if the data is changed. Otherwise the data is considered changed in place and can't be reset.
+    /// 
     /// ```rust
     /// use strunemix::*;
     /// 
@@ -126,14 +321,13 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn get_data_mut(&mut self, name: impl QueryNameTrait<T>) -> Result<Option<&mut U>, StrunemixError>{
         let name = name.to_attrname()?;
-        let found = self.map.get_mut(&name).map(|(data, _)| data)
-        .expect(ERR_MISSING_KEY)
-        .as_mut();
+        let (_, field) = self.field_mut(&name)?;
+
+        field.mark_given_mut();
+
+        let found = field.data.as_mut();
 
         Ok(found)
     }
@@ -163,18 +357,15 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn get_info_mut(&mut self, name: impl QueryNameTrait<T>) -> Result<&mut A, StrunemixError>{
         let name = name.to_attrname()?;
-        let found = self.map.get_mut(&name).map(|(_, info )| info)
-        .expect(ERR_MISSING_KEY);
+        let (_, field) = self.field_mut(&name)?;
 
-        Ok(found)
+        Ok(&mut field.info)
     }
 
-    /// Set the data of a field by its name (string or enum) and the data enum value
+    /// Set the data of a field by its name (string or enum) and the data enum value.
+    /// The data is refused if it does not satisfy the constraints declared on the field.
     /// 
     /// ```rust
     /// use strunemix::*;
@@ -200,22 +391,20 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn set_data(&mut self, name: impl QueryNameTrait<T>, data: U) -> Result<(), StrunemixError> {
         let name = name.to_attrname()?;
-        self.map.get_mut(&name)
-        .expect(ERR_MISSING_KEY)
-        .0 = Some(data);
+        data.check()?;
+        self.replace_data(&name, Some(data))?;
 
         Ok(())
     }
 
     /// Set the data of a field from its name (string or enum) and a string data.
     /// Be sure to implement [StrunemixParsableData] on the enum of names to allow the conversion from the string to the enum of datas and use this method.
+    /// The data is refused if it does not satisfy the constraints declared on the field.
     /// 
     /// ```rust
+    /// use std::borrow::Cow;
     /// use strunemix::*;
     /// 
     /// #[derive(Debug, Default)]
@@ -228,7 +417,7 @@ where
     /// }
     /// 
     /// impl StrunemixParsableData<'_, FooAttrData> for FooAttrName {
-    ///   fn add_data(&self, data: &str) -> Result<FooAttrData, StrunemixParseError> {
+    ///   fn add_data(&self, data: Cow<str>) -> Result<FooAttrData, StrunemixParseError> {
     ///     match self {
     ///       FooAttrName::Bar => Ok(FooAttrData::Bar(data.parse()?)),
     ///     }
@@ -247,19 +436,13 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn set_data_str<'a>(&mut self, name: impl QueryNameTrait<T>, data: &'a str) -> Result<(), StrunemixError>
     where
         T: StrunemixParsableData<'a, U>
     {
         let name = name.to_attrname()?;
-        let data = name.add_data(data)?;
-
-        self.map.get_mut(&name)
-        .expect(ERR_MISSING_KEY)
-        .0 = Some(data);
+        let data = crate::__private::parse_field(&name, data)?;
+        self.replace_data(&name, Some(data))?;
 
         Ok(())
     }
@@ -289,14 +472,9 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the key does not exist, it should't happen.
     pub fn remove_data(&mut self, name: impl QueryNameTrait<T>) -> Result<(), StrunemixError> {
         let name = name.to_attrname()?;
-        self.map.get_mut(&name)
-        .expect(ERR_MISSING_KEY)
-        .0 = None;
+        self.replace_data(&name, None)?;
 
         Ok(())
     }
@@ -326,14 +504,57 @@ where
     /// 
     pub fn set_info(&mut self, name: impl QueryNameTrait<T>, info: A) -> Result<(), StrunemixError> {
         let name = name.to_attrname()?;
-        self.map.get_mut(&name)
-        .expect(ERR_MISSING_KEY)
-        .1 = info;
+        let index = self.index_of(&name)?;
+        let field = &mut self.fields[index];
+        let old = core::mem::replace(&mut field.info, info);
+
+        #[cfg(feature = "observe")]
+        self.observers.notify(&name, Change::Info { old: &old, new: &field.info });
+        #[cfg(not(feature = "observe"))]
+        drop(old);
 
         Ok(())
     }
 
-    /// Check that all the fields have data
+    /// Set the info of a field by its name, checking that the info belongs to the field, when each field has its own type of info
+    /// in an enum of infos generated with `#[strunemix_info]` (see [StrunemixInfo]).
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_info]
+    /// struct Person {
+    ///   #[strunemix(info = usize)]
+    ///   pseudo: String,
+    ///   #[strunemix(info = (i32, i32))]
+    ///   age: i32,
+    /// }
+    /// 
+    /// let mut form = Person::empty_form_with(PersonAttrInfo::default_for);
+    /// 
+    /// assert!(form.set_info_checked("age", PersonAttrInfo::Age((0, 150))).is_ok());
+    /// assert!(matches!(
+    ///     form.set_info_checked("age", PersonAttrInfo::Pseudo(32)),
+    ///     Err(StrunemixError::WrongInfo { field: "age", info: "pseudo" })
+    /// ));
+    /// ```
+    pub fn set_info_checked(&mut self, name: impl QueryNameTrait<T>, info: A) -> Result<(), StrunemixError>
+    where
+        A: StrunemixInfo<T>,
+        T: for<'a> From<&'a A>
+    {
+        let name = name.to_attrname()?;
+        let info_name = info.name();
+
+        if info_name != name {
+            return Err(StrunemixError::WrongInfo { field: name.get_str(), info: info_name.get_str() });
+        }
+
+        self.set_info(name, info)
+    }
+
+    /// Check that all the fields have data, a field whose name is not known, or not at its position, never has.
     /// 
     /// ```rust
     /// use strunemix::*;
@@ -358,7 +579,10 @@ where
     /// 
     /// ```
     pub fn is_complete(&self) -> bool {
-        self.map.iter().all(|(_, (data, _))| data.is_some())
+        self.names.iter()
+        .zip(self.fields.iter())
+        .enumerate()
+        .all(|(index, (name, field))| name.as_ref().is_some_and(|name| name.index() == index) && field.data.is_some())
     }
 
     /// Convert the form into an array of data if all the fields have data
@@ -387,15 +611,84 @@ where
     /// assert_eq!(data, [FooAttrData::Bar(42), FooAttrData::Baz(true)]);
     /// ```
     pub fn to_data_array(self) -> Result<[U; N], StrunemixError>{
+        if !self.is_complete() {
+            return Err(StrunemixError::IncompleteForm);
+        }
+        let iter = self.fields.into_iter().map_while(|field| field.data);
+
+        array_init::from_iter(iter).ok_or(StrunemixError::IncompleteForm)
+    }
 
-        if !self.is_complete(){
+    /// Clone the data of the form into an array if all the fields have data, the form is kept as is
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Foo {
+    ///   bar: i32,
+    ///   baz: bool
+    /// }
+    /// 
+    /// let mut foo_form = Foo::empty_form::<()>();
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42));
+    /// assert!(foo_form.to_data_array_cloned().is_err());
+    /// 
+    /// foo_form.set_data(FooAttrName::Baz, FooAttrData::Baz(true));
+    /// assert_eq!(foo_form.to_data_array_cloned().unwrap(), [FooAttrData::Bar(42), FooAttrData::Baz(true)]);
+    /// assert!(foo_form.is_complete());
+    /// ```
+    pub fn to_data_array_cloned(&self) -> Result<[U; N], StrunemixError>
+    where
+        U: Clone
+    {
+        if !self.is_complete() {
             return Err(StrunemixError::IncompleteForm);
         }
+        let iter = self.fields.iter().map_while(|field| field.data.clone());
 
-        let iter = self.map.into_iter().map(|(_, (data, _))| data.unwrap());
+        array_init::from_iter(iter).ok_or(StrunemixError::IncompleteForm)
+    }
 
-        let vec = array_init::from_iter(iter).unwrap();
-        Ok(vec)
+    /// Move the data out of the form, each at the position of its field.
+    pub(crate) fn into_data_slots(self) -> [Option<U>; N] {
+        self.fields.map(|field| field.data)
+    }
+
+    /// Clone the data of the form, each at the position of its field.
+    pub(crate) fn to_data_slots(&self) -> [Option<U>; N]
+    where
+        U: Clone
+    {
+        self.fields.each_ref().map(|field| field.data.clone())
+    }
+
+    /// Take the data out of the form, each at the position of its field, the fields without data take the one at their position in `fallbacks`.
+    pub(crate) fn take_data_slots_or(&mut self, fallbacks: Vec<Option<U>>) -> [Option<U>; N] {
+        let mut fallbacks = fallbacks.into_iter();
+
+        self.fields.each_mut().map(|field| {
+            let fallback = fallbacks.next().flatten();
+            field.data.take().or(fallback)
+        })
+    }
+
+    /// The names of the fields that have no data, nor one at their position in `fallbacks`
+    pub(crate) fn missing_names(&self, fallbacks: &[Option<U>]) -> Vec<T> {
+        self.fields.iter()
+        .enumerate()
+        .filter(|(index, field)| field.data.is_none() && fallbacks.get(*index).is_none_or(Option::is_none))
+        .filter_map(|(index, _)| T::from_index(index))
+        .collect()
+    }
+
+    /// Check the data of every field, or the one at its position in `fallbacks` if it has none, against the constraints declared on it
+    pub(crate) fn check_data(&self, fallbacks: &[Option<U>]) -> Result<(), StrunemixConstraintError> {
+        self.fields.iter()
+        .enumerate()
+        .filter_map(|(index, field)| field.data.as_ref().or(fallbacks.get(index).and_then(Option::as_ref)))
+        .try_for_each(|data| data.check())
     }
 
     /// Convert the form into an array of info
@@ -421,7 +714,353 @@ where
     /// 
     /// assert_eq!(info, [&AdditionalMetadata("bar".to_string()), &AdditionalMetadata(String::new())]);
     pub fn get_info_array(&self) -> Vec<&A>{
-        self.map.iter().map(|(_, (_, info))| info).collect()
+        self.fields.iter().map(|field| &field.info).collect()
+    }
+
+    /// Check the form against a [Validator], and return a report of every rule that failed.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    ///   baz: bool
+    /// }
+    /// 
+    /// let validator = FormValidator::new()
+    ///     .required(FooAttrName::Bar)
+    ///     .required(FooAttrName::Baz);
+    /// 
+    /// let mut foo_form = Foo::empty_form::<()>();
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42));
+    /// 
+    /// let report = foo_form.validate(&validator).unwrap_err();
+    /// assert_eq!(report.len(), 1);
+    /// assert_eq!(report.for_field(FooAttrName::Baz).next().unwrap().message(), "is required");
+    /// ```
+    pub fn validate(&self, validator: &impl Validator<T, U, N, A>) -> Result<(), ValidationReport> {
+        let mut report = ValidationReport::new();
+        validator.validate(self, &mut report);
+
+        match report.is_valid() {
+            true => Ok(()),
+            false => Err(report),
+        }
+    }
+
+    /// Check the form against a [Validator] like [`StrunemixForm::validate`], and store the messages of the errors of each field in its info.
+    /// The info of the valid fields are given an empty list of errors.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    ///   baz: bool
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let validator = FormValidator::new()
+    ///     .required(FooAttrName::Bar)
+    ///     .required(FooAttrName::Baz);
+    /// 
+    /// let mut foo_form = Foo::empty_form::<Vec<String>>();
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42))?;
+    /// 
+    /// assert!(foo_form.validate_and_store(&validator).is_err());
+    /// assert_eq!(foo_form.get_info("bar")?, &Vec::<String>::new());
+    /// assert_eq!(foo_form.get_info("baz")?, &vec!["is required".to_string()]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_and_store(&mut self, validator: &impl Validator<T, U, N, A>) -> Result<(), ValidationReport>
+    where
+        A: ValidationInfo
+    {
+        let result = self.validate(validator);
+        let report = result.as_ref().err();
+
+        for (name, field) in self.names.iter().zip(self.fields.iter_mut()) {
+            let errors = report.iter()
+                .flat_map(|report| report.errors())
+                .filter(|error| name.is_some() && error.name::<T>().as_ref() == name.as_ref())
+                .map(|error| error.message().to_string())
+                .collect();
+
+            field.info.set_errors(errors);
+        }
+
+        result
+    }
+
+    /// Get the fields whose data was set or removed since the form was created, or since they were [reset](StrunemixForm::reset).
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    ///   baz: bool
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut foo_form = Foo {bar: 42, baz: true}.to_form::<()>();
+    /// assert!(foo_form.touched_fields().is_empty());
+    /// 
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42))?;
+    /// assert_eq!(foo_form.touched_fields(), [&FooAttrName::Bar]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn touched_fields(&self) -> Vec<&T> {
+        self.named_fields()
+        .filter(|(_, field)| field.is_touched())
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// Get the fields whose data differs from the one they had when the form was created.
+    /// 
+    /// A field changed in place with [`StrunemixForm::get_data_mut`] before being set is always considered dirty.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    ///   baz: bool
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut foo_form = Foo {bar: 42, baz: true}.to_form::<()>();
+    /// 
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42))?;
+    /// foo_form.set_data(FooAttrName::Baz, FooAttrData::Baz(false))?;
+    /// assert_eq!(foo_form.dirty_fields(), [&FooAttrName::Baz]);
+    /// 
+    /// foo_form.set_data(FooAttrName::Baz, FooAttrData::Baz(true))?;
+    /// assert!(foo_form.dirty_fields().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dirty_fields(&self) -> Vec<&T>
+    where
+        U: PartialEq
+    {
+        self.named_fields()
+        .filter(|(_, field)| field.is_dirty())
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// Check if the data of any field differs from the one it had when the form was created.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut foo_form = Foo::empty_form::<()>();
+    /// assert!(!foo_form.is_dirty());
+    /// 
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(42))?;
+    /// assert!(foo_form.is_dirty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_dirty(&self) -> bool
+    where
+        U: PartialEq
+    {
+        self.fields.iter().any(|field| field.is_dirty())
+    }
+
+    /// Put back the data a field had when the form was created, and mark it as untouched.
+    /// 
+    /// Fails with [`StrunemixError::InitialDataLost`] if the field was changed in place with [`StrunemixForm::get_data_mut`] before being set,
+    /// the field is then left as is.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut foo_form = Foo {bar: 42}.to_form::<()>();
+    /// 
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(666))?;
+    /// foo_form.remove_data(FooAttrName::Bar)?;
+    /// foo_form.reset(FooAttrName::Bar)?;
+    /// 
+    /// assert_eq!(foo_form.get_data(FooAttrName::Bar)?, Some(&FooAttrData::Bar(42)));
+    /// assert!(foo_form.touched_fields().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset(&mut self, name: impl QueryNameTrait<T>) -> Result<(), StrunemixError> {
+        let name = name.to_attrname()?;
+        let (index, field) = self.field_mut(&name)?;
+
+        match field.touch {
+            Touch::Untouched => Ok(()),
+            Touch::ChangedInPlace => Err(StrunemixError::InitialDataLost),
+            Touch::Touched(_) => {
+                let previous = field.restore(Previous::Initial);
+                self.notify_data(index, &previous);
+                self.record(index, previous);
+                Ok(())
+            }
+        }
+    }
+
+    /// Replace the data of a field, and record what is needed to put the previous data back.
+    pub(crate) fn replace_data(&mut self, name: &T, data: Option<U>) -> Result<(), StrunemixError> {
+        let (index, field) = self.field_mut(name)?;
+        let previous = field.replace(data);
+        self.notify_data(index, &previous);
+        self.record(index, previous);
+        Ok(())
+    }
+
+    /// Tell the observers that the data of a field was replaced, which needs the `observe` feature.
+    #[cfg(not(feature = "observe"))]
+    pub(crate) fn notify_data(&mut self, _index: usize, _previous: &Previous<U>) {}
+
+    /// Get the position of a field in the form from a reference to its name
+    fn index_of(&self, name: &T) -> Result<usize, StrunemixError> {
+        let index = name.index();
+
+        match self.names.get(index) {
+            Some(Some(found)) if found == name => Ok(index),
+            _ => Err(StrunemixError::MissingField(name.get_str())),
+        }
+    }
+
+    /// Get a field from a reference to its name
+    fn field(&self, name: &T) -> Result<&Field<U, A>, StrunemixError> {
+        let index = self.index_of(name)?;
+        Ok(&self.fields[index])
+    }
+
+    /// Get a field and its position in the form from a reference to its name
+    fn field_mut(&mut self, name: &T) -> Result<(usize, &mut Field<U, A>), StrunemixError> {
+        let index = self.index_of(name)?;
+        Ok((index, &mut self.fields[index]))
+    }
+
+    /// Get the data of a field from a reference to its name
+    pub(crate) fn data_of(&self, name: &T) -> Option<&U> {
+        self.field(name).ok().and_then(|field| field.data.as_ref())
+    }
+
+    /// Write the filled fields of the form as a single logfmt line, like `pseudo=Anna age=25`.
+    /// Values that contain whitespaces, `=` or quotes are quoted and escaped, fields without data are left out.
+    /// Be sure to implement [StrunemixDisplayableData] on the enum of datas to use this method.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// #[strunemix_display]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut form = Person::empty_form::<()>();
+    /// assert_eq!(form.to_logfmt(), "");
+    /// 
+    /// form.set_data("pseudo", PersonAttrData::Pseudo("Anna Lee".to_string()))?;
+    /// assert_eq!(form.to_logfmt(), r#"pseudo="Anna Lee""#);
+    /// 
+    /// form.set_data("age", PersonAttrData::Age(25))?;
+    /// assert_eq!(form.to_logfmt(), r#"pseudo="Anna Lee" age=25"#);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_logfmt(&self) -> String
+    where
+        U: StrunemixDisplayableData<T>
+    {
+        let mut line = String::new();
+
+        for (name, field) in self.named_fields() {
+            if let Some(data) = &field.data {
+                logfmt::write_pair(&mut line, name.get_str(), &data.to_data_string());
+            }
+        }
+
+        line
+    }
+
+    /// Set the data of the fields found in a logfmt line, like `pseudo=Anna age=25`.
+    /// Keys that are not fields of the struct are ignored, and the last value wins if a key is repeated.
+    /// The line is set in a [`StrunemixForm::transaction`], so the form is left untouched if any of its values is refused.
+    /// Be sure to implement [StrunemixParsableData] on the enum of names to use this method.
+    /// 
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// impl StrunemixParsableData<'_, PersonAttrData> for PersonAttrName {
+    ///   fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
+    ///     match self {
+    ///       PersonAttrName::Pseudo => Ok(PersonAttrData::Pseudo(data.to_string())),
+    ///       PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?)),
+    ///     }
+    ///   }
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut form = Person::empty_form::<()>();
+    /// form.set_logfmt(r#"level=info pseudo="Anna \"Nan\" Lee" age=25"#)?;
+    /// 
+    /// assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo(r#"Anna "Nan" Lee"#.to_string())));
+    /// assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_logfmt(&mut self, line: &str) -> Result<(), StrunemixError>
+    where
+        T: for<'b> StrunemixParsableData<'b, U>
+    {
+        self.transaction(|tx| {
+            for pair in LogfmtPairs::new(line) {
+                let (key, value) = pair?;
+
+                if let Ok(name) = <T as StrunemixName>::from_str(key) {
+                    tx.set_data_str(name, &value)?;
+                }
+            }
+
+            Ok(())
+        })
     }
 }
 
@@ -0,0 +1,360 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::*;
+
+/// A change of the data of a field, enough to put the field back as it was.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Edit<U> {
+    /// The position of the field in the form.
+    index: usize,
+    previous: Previous<U>,
+}
+
+/// The edits recorded on a form, grouped by what is undone at once.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct History<U> {
+    capacity: usize,
+    undo: VecDeque<Vec<Edit<U>>>,
+    redo: Vec<Vec<Edit<U>>>,
+    /// The edits of the group being recorded, and how many times it was opened.
+    group: Option<(Vec<Edit<U>>, usize)>,
+}
+
+impl<U> History<U> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self { capacity, undo: VecDeque::new(), redo: Vec::new(), group: None }
+    }
+
+    /// Record a new edit, which makes the undone edits impossible to redo.
+    pub(crate) fn record(&mut self, index: usize, previous: Previous<U>) {
+        self.redo.clear();
+
+        match &mut self.group {
+            Some((edits, _)) => edits.push(Edit { index, previous }),
+            None => self.push_undo(vec![Edit { index, previous }]),
+        }
+    }
+
+    fn push_undo(&mut self, edits: Vec<Edit<U>>) {
+        if self.capacity == 0 || edits.is_empty() {
+            return;
+        }
+
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(edits);
+    }
+
+    /// Record edits made at once, in the open group if any.
+    fn record_all(&mut self, edits: Vec<Edit<U>>) {
+        if edits.is_empty() {
+            return;
+        }
+        self.redo.clear();
+
+        match &mut self.group {
+            Some((group, _)) => group.extend(edits),
+            None => self.push_undo(edits),
+        }
+    }
+
+    fn begin_group(&mut self) {
+        match &mut self.group {
+            Some((_, depth)) => *depth += 1,
+            None => self.group = Some((Vec::new(), 1)),
+        }
+    }
+
+    fn end_group(&mut self) {
+        match self.group.take() {
+            Some((edits, 1)) => self.push_undo(edits),
+            Some((edits, depth)) => self.group = Some((edits, depth - 1)),
+            None => {},
+        }
+    }
+}
+
+impl<T, U, const N: usize, A> StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    /// Start recording the changes made by [`StrunemixForm::set_data`], [`StrunemixForm::set_data_str`], [`StrunemixForm::remove_data`] and [`StrunemixForm::reset`],
+    /// so that they can be undone. Only the last `capacity` edits, or groups of edits, are kept.
+    ///
+    /// Changes made in place with [`StrunemixForm::get_data_mut`] are not recorded.
+    /// Enabling the history again clears it.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Foo {
+    ///   bar: i32,
+    /// }
+    ///
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut foo_form = Foo {bar: 1}.to_form::<()>();
+    /// foo_form.enable_history(10);
+    ///
+    /// foo_form.set_data(FooAttrName::Bar, FooAttrData::Bar(2))?;
+    /// foo_form.remove_data(FooAttrName::Bar)?;
+    ///
+    /// assert!(foo_form.undo());
+    /// assert_eq!(foo_form.get_data(FooAttrName::Bar)?, Some(&FooAttrData::Bar(2)));
+    ///
+    /// assert!(foo_form.undo());
+    /// assert_eq!(foo_form.get_data(FooAttrName::Bar)?, Some(&FooAttrData::Bar(1)));
+    /// assert!(!foo_form.undo());
+    ///
+    /// assert!(foo_form.redo());
+    /// assert_eq!(foo_form.get_data(FooAttrName::Bar)?, Some(&FooAttrData::Bar(2)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_history(&mut self, capacity: usize) {
+        self.history = Some(History::new(capacity));
+    }
+
+    /// Stop recording the changes and forget the recorded ones.
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Check that there are changes to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history.as_ref().is_some_and(|history| !history.undo.is_empty())
+    }
+
+    /// Check that there are undone changes to redo.
+    pub fn can_redo(&self) -> bool {
+        self.history.as_ref().is_some_and(|history| !history.redo.is_empty())
+    }
+
+    /// Undo the last recorded change, or group of changes. Return false if there was nothing to undo,
+    /// or if it is called in a [`StrunemixForm::transaction`], where nothing is undone.
+    ///
+    /// An open group is closed first, as if [`StrunemixForm::end_group`] was called as many times as needed.
+    pub fn undo(&mut self) -> bool {
+        if self.transaction.is_some() {
+            return false;
+        }
+        let Some(mut history) = self.history.take() else {
+            return false;
+        };
+
+        while history.group.is_some() {
+            history.end_group();
+        }
+
+        let done = match history.undo.pop_back() {
+            Some(edits) => {
+                let mut edits = self.apply_edits(edits.into_iter().rev());
+                edits.reverse();
+                history.redo.push(edits);
+                true
+            },
+            None => false,
+        };
+
+        self.history = Some(history);
+        done
+    }
+
+    /// Redo the last undone change, or group of changes. Return false if there was nothing to redo,
+    /// or if it is called in a [`StrunemixForm::transaction`], where nothing is redone.
+    pub fn redo(&mut self) -> bool {
+        if self.transaction.is_some() {
+            return false;
+        }
+        let Some(mut history) = self.history.take() else {
+            return false;
+        };
+
+        while history.group.is_some() {
+            history.end_group();
+        }
+
+        let done = match history.redo.pop() {
+            Some(edits) => {
+                let edits = self.apply_edits(edits.into_iter());
+                history.push_undo(edits);
+                true
+            },
+            None => false,
+        };
+
+        self.history = Some(history);
+        done
+    }
+
+    /// Start a group of changes that are undone and redone at once, until [`StrunemixForm::end_group`] is called.
+    /// Groups can be nested, the changes are then recorded with the outermost group.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut form = Person::empty_form::<()>();
+    /// form.enable_history(10);
+    ///
+    /// form.begin_group();
+    /// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string()))?;
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42))?;
+    /// form.end_group();
+    ///
+    /// assert!(form.undo());
+    /// assert_eq!(form.get_data(PersonAttrName::Pseudo)?, None);
+    /// assert_eq!(form.get_data(PersonAttrName::Age)?, None);
+    /// assert!(!form.can_undo());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn begin_group(&mut self) {
+        if let Some(history) = self.history.as_mut() {
+            history.begin_group();
+        }
+    }
+
+    /// End the group started by [`StrunemixForm::begin_group`].
+    pub fn end_group(&mut self) {
+        if let Some(history) = self.history.as_mut() {
+            history.end_group();
+        }
+    }
+
+    /// Run the function in a group of changes, that are undone and redone at once.
+    pub fn group<R>(&mut self, edits: impl FnOnce(&mut Self) -> R) -> R {
+        self.begin_group();
+        let res = edits(self);
+        self.end_group();
+        res
+    }
+
+    /// Run the function on the form, and put back the data of the fields as they were before if it fails.
+    /// The changes made by the function are undone at once if the history is enabled.
+    ///
+    /// Only the changes of the data made by [`StrunemixForm::set_data`], [`StrunemixForm::set_data_str`], [`StrunemixForm::remove_data`]
+    /// and [`StrunemixForm::reset`] are rolled back, not the ones of the info nor the ones made in place.
+    /// The changes are kept apart from the history until the function returns, so [`StrunemixForm::undo`] and [`StrunemixForm::redo`] do nothing in it.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// #[strunemix_parse]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let mut form = Person::empty_form::<()>();
+    ///
+    /// let res = form.transaction(|tx| {
+    ///     tx.set_data_str("pseudo", "John")?;
+    ///     tx.set_data_str("age", "old")
+    /// });
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(form.get_data("pseudo")?, None);
+    ///
+    /// form.transaction(|tx| {
+    ///     tx.set_data_str("pseudo", "John")?;
+    ///     tx.set_data_str("age", "42")
+    /// })?;
+    ///
+    /// assert!(form.is_complete());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transaction<R, E>(&mut self, changes: impl FnOnce(&mut Self) -> Result<R, E>) -> Result<R, E> {
+        let outer = self.transaction.replace(Vec::new());
+        let res = changes(self);
+        let edits = core::mem::replace(&mut self.transaction, outer).unwrap_or_default();
+
+        match (&res, self.transaction.as_mut(), self.history.as_mut()) {
+            (Ok(_), Some(outer), _) => outer.extend(edits),
+            (Ok(_), None, Some(history)) => history.record_all(edits),
+            (Ok(_), None, None) => {},
+            (Err(_), _, _) => {
+                self.apply_edits(edits.into_iter().rev());
+            },
+        }
+
+        res
+    }
+
+    /// Set the data of many fields from strings, either all of them or none of them.
+    /// Every value is parsed, and all the errors are returned if any of them fails.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// #[strunemix_parse]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    ///   height: f32,
+    /// }
+    ///
+    /// let mut form = Person::empty_form::<()>();
+    ///
+    /// let errors = form.set_many_str([("pseudo", "John"), ("age", "old"), ("height", "tall")]).unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(form.get_data("pseudo").unwrap(), None);
+    ///
+    /// assert!(form.set_many_str([("pseudo", "John"), ("age", "42"), ("height", "1.8")]).is_ok());
+    /// assert!(form.is_complete());
+    /// ```
+    pub fn set_many_str<'a, Q>(&mut self, datas: impl IntoIterator<Item = (Q, &'a str)>) -> Result<(), Vec<StrunemixError>>
+    where
+        Q: QueryNameTrait<T>,
+        T: StrunemixParsableData<'a, U>,
+    {
+        self.transaction(|tx| {
+            let errors: Vec<StrunemixError> = datas.into_iter()
+            .filter_map(|(name, data)| tx.set_data_str(name, data).err())
+            .collect();
+
+            match errors.is_empty() {
+                true => Ok(()),
+                false => Err(errors),
+            }
+        })
+    }
+
+    /// Record an edit of a field in the running transaction, or else in the history if it is enabled.
+    pub(crate) fn record(&mut self, index: usize, previous: Previous<U>) {
+        match (self.transaction.as_mut(), self.history.as_mut()) {
+            (Some(edits), _) => edits.push(Edit { index, previous }),
+            (None, Some(history)) => history.record(index, previous),
+            (None, None) => {},
+        }
+    }
+
+    /// Put back the fields as they were before the edits, and return the edits that put them back as they are now.
+    fn apply_edits(&mut self, edits: impl Iterator<Item = Edit<U>>) -> Vec<Edit<U>> {
+        edits.filter_map(|Edit { index, previous }| {
+            let field = self.fields.get_mut(index)?;
+            let previous = field.restore(previous);
+            self.notify_data(index, &previous);
+            Some(Edit { index, previous })
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::*;
+
+/// Trait implemented automatically on the enums of infos generated with `#[strunemix_info]`,
+/// to give each field of a form its own type of info.
+///
+/// The type of the info of a field is declared with `#[strunemix(info = Type)]` on the field, `()` if it is not declared.
+/// [`StrunemixForm::set_info_checked`] refuses the info of a field set on another one.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Bounds { min: i32, max: i32 }
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct MaxLength(usize);
+///
+/// #[derive(Strunemix)]
+/// #[strunemix_info(Debug, PartialEq)]
+/// struct Person {
+///   #[strunemix(info = MaxLength)]
+///   pseudo: String,
+///   #[strunemix(info = Bounds)]
+///   age: i32,
+///   active: bool,
+/// }
+///
+/// # fn main() -> Result<(), StrunemixError> {
+/// let mut form = Person::empty_form_with(PersonAttrInfo::default_for);
+/// form.set_info_checked("age", PersonAttrInfo::Age(Bounds { min: 0, max: 150 }))?;
+/// assert!(form.set_info_checked("active", PersonAttrInfo::Age(Bounds::default())).is_err());
+///
+/// if let PersonAttrInfo::Pseudo(max_length) = form.get_info_mut("pseudo")? {
+///     max_length.0 = 32;
+/// }
+///
+/// assert_eq!(form.get_info("pseudo")?, &PersonAttrInfo::Pseudo(MaxLength(32)));
+/// assert_eq!(form.get_info("active")?, &PersonAttrInfo::Active(()));
+/// assert_eq!(form.get_info("age")?.name(), PersonAttrName::Age);
+/// # Ok(())
+/// # }
+/// ```
+pub trait StrunemixInfo<T>
+where
+    Self: Sized,
+    T: StrunemixName,
+{
+    /// Get the info of a field with the [`Default`] value of its type.
+    fn default_for(name: &T) -> Self;
+
+    /// Get the name of the field of the info.
+    fn name<'a>(&'a self) -> T
+    where
+        T: From<&'a Self>
+    {
+        T::from(self)
+    }
+}
@@ -0,0 +1,315 @@
+use alloc::vec::Vec;
+use core::iter::{FusedIterator, Zip};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::{array, slice};
+
+use crate::*;
+
+/// An iterator over the fields of a [StrunemixForm], yielding their name, data and info.
+///
+/// Created by [`StrunemixForm::iter`].
+pub struct FormIter<'a, T, U, A> {
+    inner: Zip<slice::Iter<'a, Option<T>>, slice::Iter<'a, Field<U, A>>>,
+}
+
+impl<'a, T: Clone, U, A> Iterator for FormIter<'a, T, U, A> {
+    type Item = (T, Option<&'a U>, &'a A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, field) = self.inner.next()?;
+        Some((name.clone()?, field.data.as_ref(), &field.info))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone, U, A> ExactSizeIterator for FormIter<'_, T, U, A> {}
+
+impl<T: Clone, U, A> FusedIterator for FormIter<'_, T, U, A> {}
+
+/// A mutable access to the data of a field, yielded by [`StrunemixForm::iter_mut`].
+///
+/// The field is only touched when the data is borrowed mutably, like with [`StrunemixForm::get_data_mut`].
+pub struct DataMut<'a, T, U> {
+    data: &'a mut U,
+    touch: &'a mut Touch<U>,
+    name: PhantomData<T>,
+}
+
+impl<T, U> Deref for DataMut<'_, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        self.data
+    }
+}
+
+impl<T: StrunemixName, U: StrunemixData<T>> DerefMut for DataMut<'_, T, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        self.touch.give_mut(self.data);
+        self.data
+    }
+}
+
+/// An iterator over the fields of a [StrunemixForm], yielding their name, and mutable accesses to their data and info.
+///
+/// Created by [`StrunemixForm::iter_mut`].
+pub struct FormIterMut<'a, T, U, A> {
+    inner: Zip<slice::Iter<'a, Option<T>>, slice::IterMut<'a, Field<U, A>>>,
+}
+
+impl<'a, T: StrunemixName + Clone, U: StrunemixData<T>, A> Iterator for FormIterMut<'a, T, U, A> {
+    type Item = (T, Option<DataMut<'a, T, U>>, &'a mut A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, Field { data, info, touch }) = self.inner.next()?;
+        let data = data.as_mut().map(|data| DataMut { data, touch, name: PhantomData });
+        Some((name.clone()?, data, info))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: StrunemixName + Clone, U: StrunemixData<T>, A> ExactSizeIterator for FormIterMut<'_, T, U, A> {}
+
+impl<T: StrunemixName + Clone, U: StrunemixData<T>, A> FusedIterator for FormIterMut<'_, T, U, A> {}
+
+/// An iterator that moves the fields out of a [StrunemixForm], yielding their name, data and info.
+///
+/// Created by the [IntoIterator] implementation of [StrunemixForm].
+pub struct FormIntoIter<T, U, A, const N: usize> {
+    inner: Zip<array::IntoIter<Option<T>, N>, array::IntoIter<Field<U, A>, N>>,
+}
+
+impl<T, U, A, const N: usize> Iterator for FormIntoIter<T, U, A, N> {
+    type Item = (T, Option<U>, A);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, field) = self.inner.next()?;
+        Some((name?, field.data, field.info))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, U, A, const N: usize> ExactSizeIterator for FormIntoIter<T, U, A, N> {}
+
+impl<T, U, A, const N: usize> FusedIterator for FormIntoIter<T, U, A, N> {}
+
+impl<T, U, const N: usize, A> StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    /// Iterate over the fields of the form, in the order of the struct, with their name, data and info.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// let mut form = Person::empty_form::<bool>();
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42)).unwrap();
+    ///
+    /// let fields: Vec<_> = form.iter().collect();
+    /// assert_eq!(fields, [
+    ///     (PersonAttrName::Pseudo, None, &false),
+    ///     (PersonAttrName::Age, Some(&PersonAttrData::Age(42)), &false),
+    /// ]);
+    /// ```
+    pub fn iter(&self) -> FormIter<'_, T, U, A>
+    where
+        T: Clone
+    {
+        FormIter { inner: self.names.iter().zip(self.fields.iter()) }
+    }
+
+    /// Iterate over the fields of the form, in the order of the struct, with their name and mutable accesses to their data and info.
+    ///
+    /// Like with [`StrunemixForm::get_data_mut`], a field is touched when its data is borrowed mutably through its [DataMut],
+    /// and only dirty if its data is changed when it can be snapshotted.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// let mut form = Person {pseudo: "john".to_string(), age: 42}.to_form::<Vec<&str>>();
+    ///
+    /// for (_, data, info) in form.iter_mut() {
+    ///     if let Some(PersonAttrData::Pseudo(pseudo)) = data.as_deref() {
+    ///         if pseudo.len() < 6 {
+    ///             info.push("is too short");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(form.get_info(PersonAttrName::Pseudo).unwrap(), &["is too short"]);
+    /// assert!(form.touched_fields().is_empty());
+    /// ```
+    pub fn iter_mut(&mut self) -> FormIterMut<'_, T, U, A>
+    where
+        T: Clone
+    {
+        FormIterMut { inner: self.names.iter().zip(self.fields.iter_mut()) }
+    }
+
+    /// The names of the fields that have no data, in the order of the struct.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// let mut form = Person::empty_form::<()>();
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42)).unwrap();
+    ///
+    /// assert_eq!(form.missing_fields(), [&PersonAttrName::Pseudo]);
+    /// assert_eq!(form.filled_fields(), [&PersonAttrName::Age]);
+    /// assert_eq!(form.len_filled(), 1);
+    /// ```
+    pub fn missing_fields(&self) -> Vec<&T> {
+        self.named_fields()
+        .filter(|(_, field)| field.data.is_none())
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// The names of the fields that have data, in the order of the struct.
+    pub fn filled_fields(&self) -> Vec<&T> {
+        self.named_fields()
+        .filter(|(_, field)| field.data.is_some())
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// The number of fields that have data.
+    pub fn len_filled(&self) -> usize {
+        self.fields.iter()
+        .filter(|field| field.data.is_some())
+        .count()
+    }
+}
+
+impl<'a, T, U, const N: usize, A> IntoIterator for &'a StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq + Clone,
+    U: StrunemixData<T>
+{
+    type Item = (T, Option<&'a U>, &'a A);
+    type IntoIter = FormIter<'a, T, U, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, U, const N: usize, A> IntoIterator for &'a mut StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq + Clone,
+    U: StrunemixData<T>
+{
+    type Item = (T, Option<DataMut<'a, T, U>>, &'a mut A);
+    type IntoIter = FormIterMut<'a, T, U, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, U, const N: usize, A> IntoIterator for StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    type Item = (T, Option<U>, A);
+    type IntoIter = FormIntoIter<T, U, A, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FormIntoIter { inner: self.names.into_iter().zip(self.fields) }
+    }
+}
+
+/// Build a form from the data of some of its fields, the other fields are left empty.
+/// If the data of a field is given more than once, the last one is kept.
+///
+/// The data is taken as the initial data of the form. The data that doesn't satisfy the constraints of its field is left out,
+/// use [`StrunemixForm::set_data`] to know why some data is refused.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Strunemix)]
+/// #[strunemix_derive_data(Debug, PartialEq)]
+/// struct Person {
+///   pseudo: String,
+///   age: i32,
+///   height: f32,
+/// }
+///
+/// let mut form: StrunemixForm<_, _, 3> = [PersonAttrData::Age(42)].into_iter().collect();
+/// assert_eq!(form.missing_fields(), [&PersonAttrName::Pseudo, &PersonAttrName::Height]);
+///
+/// form.extend([PersonAttrData::Pseudo("John".to_string()), PersonAttrData::Height(1.8)]);
+/// assert!(form.is_complete());
+/// ```
+impl<T, U, const N: usize, A> FromIterator<U> for StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq + for<'a> From<&'a U>,
+    U: StrunemixData<T>,
+    A: Default
+{
+    fn from_iter<I: IntoIterator<Item = U>>(datas: I) -> Self {
+        let names = core::array::from_fn(T::from_index);
+        let mut fields: [Field<U, A>; N] = core::array::from_fn(|_| Field::new(None, A::default()));
+
+        for data in datas.into_iter().filter(|data| data.check().is_ok()) {
+            if let Some(field) = fields.get_mut(data.name().index()) {
+                field.data = Some(data);
+            }
+        }
+
+        StrunemixForm::from_fields(names, fields)
+    }
+}
+
+/// Set the data of the fields, like [`StrunemixForm::set_data`].
+/// The data that doesn't satisfy the constraints of its field, or of a field that is not in the form, is ignored,
+/// use [`StrunemixForm::set_data`] to know why some data is refused.
+impl<T, U, const N: usize, A> Extend<U> for StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq + for<'a> From<&'a U>,
+    U: StrunemixData<T>
+{
+    fn extend<I: IntoIterator<Item = U>>(&mut self, datas: I) {
+        for data in datas {
+            let name = data.name();
+            // Refused data is ignored, as documented above.
+            let _ = self.set_data(name, data);
+        }
+    }
+}
@@ -49,6 +49,7 @@
 //! If you want to build the attribute data from string values, you must implement the [`StrunemixParsableData`] trait to handle the conversion from the string data to the struct fields.
 //! 
 //! ```rust
+//! # use std::borrow::Cow;
 //! # use strunemix::*;
 //! # #[derive(Debug, PartialEq, Strunemix)]
 //! # #[strunemix_derive_data(Debug, PartialEq)]
@@ -58,7 +59,7 @@
 //! # }
 //! // Implement the trait for the enum of names
 //! impl StrunemixParsableData<'_, PersonAttrData> for PersonAttrName {
-//!   fn add_data(&self, data: &str) -> Result<PersonAttrData, StrunemixParseError> {
+//!   fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
 //!     match self {
 //!       PersonAttrName::Pseudo => Ok(PersonAttrData::Pseudo(data.to_string())),
 //!       PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?))
@@ -69,7 +70,7 @@
 //! # fn main() -> Result<(), StrunemixError> {
 //! // Build the attribute data from string values
 //! let pseudo_expected = PersonAttrData::Pseudo("Lois".to_string());
-//! let pseudo = "pseudo".field_of::<Person>()?.add_data("Lois")?;
+//! let pseudo = "pseudo".field_of::<Person>()?.add_data("Lois".into())?;
 //! assert_eq!(&pseudo_expected, &pseudo);
 //! 
 //! let lois = Person { pseudo: "Lois".to_string(), age: 25 };
@@ -84,7 +85,20 @@
 //! # Ok(())
 //! # }
 //! ```
+//! 
+//! # no_std
+//! 
+//! Strunemix works without the standard library, but needs the `alloc` crate.
+//! Disable the default features to use it in a `no_std` crate, the `std` feature is needed by the `regex` one.
+//! 
+//! ```toml
+//! strunemix = { version = "0.6", default-features = false }
+//! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(clippy::panic, clippy::unwrap_used, clippy::expect_used)]
+
+extern crate alloc;
 
 /// Implements the [`StrunemixTrait`] on a struct automatically and generate the necessary enums and traits implementations.
 /// 
@@ -169,6 +183,245 @@
 /// let age_clone = age.clone();
 /// ```
 /// 
+/// ## Display the data
+/// 
+/// `#[strunemix_display]` can be used to implement [`StrunemixDisplayableData`] on the generated enums data,
+/// and [`StrunemixDisplayableFields`] on the struct, using the [`Display`](std::fmt::Display) implementation of each field.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_display]
+/// struct Person {
+///   pseudo: String,
+///   age: i32,
+/// }
+/// 
+/// let form = Person {pseudo: "John".to_string(), age: 42}.to_form::<()>();
+/// assert_eq!(form.get_data_str("age").unwrap(), Some("42".to_string()));
+/// ```
+/// 
+/// ## Parse the data
+/// 
+/// `#[strunemix_parse]` can be used to implement [`StrunemixParsableData`] on the generated enums names,
+/// using the [`FromStr`](std::str::FromStr) implementation of each field.
+/// 
+/// Some types get a built-in handling :
+/// - `String` and `&str` take the input as is.
+/// - `Option<T>` is `None` when the input is empty or `null`.
+/// - `Vec<T>` splits the input on `,`, or on the value of `#[strunemix(delimiter = "...")]`. An empty input gives an empty `Vec`.
+/// - Tuples split the input on `,`, or on the value of `#[strunemix(separator = "...")]`. Missing elements are parsed from an empty string.
+/// - `()` ignores the input.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Debug, PartialEq, Strunemix)]
+/// #[strunemix_parse]
+/// struct Person {
+///   pseudo: String,
+///   age: Option<i32>,
+///   #[strunemix(delimiter = ";")]
+///   phones: Vec<String>,
+///   #[strunemix(separator = ":")]
+///   position: (f32, Option<f32>),
+/// }
+/// 
+/// # fn main() -> Result<(), StrunemixError> {
+/// let mut form = Person::empty_form::<()>();
+/// form.set_data_str("pseudo", "John")?;
+/// form.set_data_str("age", "")?;
+/// form.set_data_str("phones", "0102;0304")?;
+/// form.set_data_str("position", "1.5")?;
+/// 
+/// let person = Person::from_form(form)?;
+/// assert_eq!(person, Person {
+///   pseudo: "John".to_string(),
+///   age: None,
+///   phones: vec!["0102".to_string(), "0304".to_string()],
+///   position: (1.5, None),
+/// });
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// ## Normalize the input
+/// 
+/// `#[strunemix(trim, lowercase, uppercase, collapse_whitespace, transform = ...)]` can be used on a field
+/// to transform the string slices before they are parsed by [`StrunemixForm::set_data_str`], in the declared order.
+/// `transform` takes the path of a function `fn(&str) -> String`. See [`StrunemixName::normalize`].
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Debug, PartialEq, Strunemix)]
+/// #[strunemix_parse]
+/// struct Person {
+///   #[strunemix(trim, collapse_whitespace)]
+///   pseudo: String,
+///   #[strunemix(trim, lowercase)]
+///   email: String,
+/// }
+/// 
+/// # fn main() -> Result<(), StrunemixError> {
+/// let mut form = Person::empty_form::<()>();
+/// form.set_data_str("pseudo", " John   Doe ")?;
+/// form.set_data_str("email", " John@Doe.com")?;
+/// 
+/// let person = Person::from_form(form)?;
+/// assert_eq!(person, Person { pseudo: "John Doe".to_string(), email: "john@doe.com".to_string() });
+/// # Ok(())
+/// # }
+/// ```
+/// 
+/// ## Constrain the data
+/// 
+/// `#[strunemix(range(min = ..., max = ...), length(min = ..., max = ...), pattern = "...", one_of(...))]` can be used on a field
+/// to declare the constraints its data must satisfy. They are checked by [`StrunemixData::check`], when data is set in a form and when a struct is built from a form.
+/// `pattern` needs the `regex` feature, enabled by default. The constraints of an `Option` field are only checked on `Some` values.
+/// 
+#[cfg_attr(feature = "regex", doc = "```rust")]
+#[cfg_attr(not(feature = "regex"), doc = "```rust,ignore")]
+/// use strunemix::*;
+/// 
+/// #[derive(Debug, PartialEq, Strunemix)]
+/// #[strunemix_parse]
+/// struct Person {
+///   #[strunemix(length(max = 32), pattern = "^[a-z]+$")]
+///   pseudo: String,
+///   #[strunemix(range(min = 0, max = 150))]
+///   age: i32,
+///   #[strunemix(one_of("light", "dark"))]
+///   theme: String,
+/// }
+/// 
+/// let mut form = Person::empty_form::<()>();
+/// 
+/// match form.set_data_str("age", "200") {
+///     Err(StrunemixError::Constraint(error)) => {
+///         assert_eq!(error.field, "age");
+///         assert_eq!(error.constraint, StrunemixConstraint::Range { min: Some("0"), max: Some("150") });
+///     },
+///     _ => panic!("the age should be refused"),
+/// }
+/// ```
+/// 
+/// An invalid regular expression in `pattern` is refused at compile time.
+/// 
+/// ```rust,compile_fail
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// struct Person {
+///   #[strunemix(pattern = "^[a-z+$")]
+///   pseudo: String,
+/// }
+/// ```
+/// 
+/// ## Default data
+/// 
+/// `#[strunemix(default)]` can be used on a field to give it the [`Default`] value of its type as default data, or `#[strunemix(default = path)]` to call a function returning it.
+/// It is returned by [`StrunemixData::default_data`], and used by [`StrunemixTrait::from_form_or_default`] for the fields left without data.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Debug, PartialEq, Strunemix)]
+/// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+/// struct Person {
+///   pseudo: String,
+///   #[strunemix(default)]
+///   tags: Vec<String>,
+/// }
+/// 
+/// let mut form = Person::empty_form::<()>();
+/// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string())).unwrap();
+/// 
+/// let person = Person::from_form_or_default(form).unwrap();
+/// assert_eq!(person, Person { pseudo: "John".to_string(), tags: vec![] });
+/// ```
+/// 
+/// ## Metadata
+/// 
+/// `#[strunemix(label = "...", placeholder = "...", meta(key = "value", ...))]` can be used on a field to attach metadata to it,
+/// for example to generate a user interface. They are returned by [`StrunemixName::label`], [`StrunemixName::placeholder`] and [`StrunemixName::meta`],
+/// along with the doc comments of the field by [`StrunemixName::description`] and its type by [`StrunemixName::type_name`].
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// struct Person {
+///   /// How the person wants to be called.
+///   #[strunemix(label = "Pseudo", placeholder = "john_doe", meta(widget = "text"))]
+///   pseudo: String,
+///   age: Option<u8>,
+/// }
+/// 
+/// assert_eq!(PersonAttrName::Pseudo.description(), Some("How the person wants to be called."));
+/// assert_eq!(PersonAttrName::Pseudo.meta("widget"), Some("text"));
+/// assert_eq!(PersonAttrName::Age.label(), "age");
+/// assert_eq!(PersonAttrName::Age.type_name(), "Option<u8>");
+/// ```
+/// 
+/// ## Info of each field
+/// 
+/// `#[strunemix_info]` can be used on the struct to generate an enum of infos, with a variant for each field holding the type declared with `#[strunemix(info = Type)]` on it,
+/// so that each field of a form gets its own type of info, see [`StrunemixInfo`]. Macros can be derived on it with `#[strunemix_info(Debug, Clone, ...)]`.
+/// 
+/// ## Names lookup
+/// 
+/// By default, a string is turned into a name only if it is exactly the name of the field.
+/// `#[strunemix_names(case_insensitive)]` can be used on the struct to ignore the case, and `#[strunemix_names(normalized)]` to also ignore underscores and dashes,
+/// so that `first_name`, `firstName` and `first-name` are the same name (see [`StrunemixNameMatching`]).
+/// When no name matches, the closest ones are suggested in the error.
+/// 
+/// ```rust
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_names(normalized)]
+/// struct Person {
+///   first_name: String,
+///   age: i32,
+/// }
+/// 
+/// assert_eq!("firstName".parse(), Ok(PersonAttrName::FirstName));
+/// 
+/// let error = "agee".parse::<PersonAttrName>().unwrap_err();
+/// assert_eq!(error.to_string(), "The string 'agee' is not a valid for the enum PersonAttrName, did you mean 'age'?");
+/// ```
+/// 
+/// Two fields can't match the same strings, which is checked at compile time.
+/// 
+/// ```rust,compile_fail
+/// use strunemix::*;
+/// 
+/// #[derive(Strunemix)]
+/// #[strunemix_names(normalized)]
+/// struct Person {
+///   first_name: String,
+///   firstname: String,
+/// }
+/// ```
+/// 
+/// ## Dynamic forms
+/// 
+/// `#[strunemix_dyn]` can be used on the struct to implement [`StrunemixDynData`] on the enum of data,
+/// so that a [`DynForm`] with the same fields converts into a form of the struct, and back.
+/// The types of the fields must implement [`StrunemixDynValue`].
+/// 
+/// ## Reflection
+/// 
+/// `#[strunemix_reflect]` can be used on the struct to implement [`StrunemixDyn`] on it, to access its fields by name through a `dyn StrunemixDyn`.
+/// The types of the fields must implement [`Debug`](core::fmt::Debug) and be `'static`, and the enum of names must implement [`StrunemixParsableData`].
+/// 
+/// ## Registry
+/// 
+/// `#[strunemix_register]` can be used on a struct without generics to add it to the `StrunemixRegistry`, which builds it from its path or name and string data.
+/// It implies `#[strunemix_reflect]`, and needs the `registry` feature.
+/// 
 /// ## Derive Macros on the enums names and data
 /// 
 /// `#[strunemix_derive]` can be used to derive some macros on both the generated enums names and data.
@@ -180,9 +433,83 @@ mod top;
 mod form;
 mod name;
 mod error;
+mod logfmt;
+mod transform;
+mod validation;
+mod constraint;
+mod history;
+mod iter;
+mod dynamic;
+mod reflect;
+mod info;
+mod lookup;
+#[cfg(feature = "observe")]
+mod observe;
+#[cfg(feature = "registry")]
+mod registry;
     
 pub use crate::name::*;
 pub use crate::data::*;
 pub use crate::form::*;
 pub use crate::top::*;
-pub use crate::error::*;
\ No newline at end of file
+pub use crate::error::*;
+pub use crate::transform::*;
+pub use crate::validation::*;
+pub use crate::constraint::*;
+pub use crate::iter::*;
+pub use crate::dynamic::*;
+pub use crate::reflect::*;
+pub use crate::info::*;
+pub use crate::lookup::StrunemixNameMatching;
+#[cfg(feature = "registry")]
+pub use crate::registry::*;
+#[cfg(feature = "observe")]
+pub use crate::observe::Change;
+
+/// Check a value against the regular expression of a `#[strunemix(pattern = "...")]` field attribute.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strunemix_pattern {
+    ($pattern:literal, $value:expr) => {{
+        static PATTERN: $crate::StrunemixPattern = $crate::StrunemixPattern::new($pattern);
+        PATTERN.is_match($value)
+    }};
+}
+
+/// Refuse the `#[strunemix(pattern = "...")]` field attribute without the `regex` feature.
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strunemix_pattern {
+    ($pattern:literal, $value:expr) => {
+        ::core::compile_error!("the `pattern` constraint requires the `regex` feature of strunemix")
+    };
+}
+
+/// Paths used by the derived code, so that it works in `no_std` crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::borrow::Cow;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "registry")]
+    pub use linkme;
+
+    pub use crate::__strunemix_pattern as pattern;
+
+    use crate::{StrunemixData, StrunemixError, StrunemixFieldParseError, StrunemixParsableData};
+
+    /// Parse the data of a field and check its constraints, as done by [StrunemixForm::set_data_str](crate::StrunemixForm::set_data_str).
+    pub fn parse_field<'a, T, U>(name: &T, data: &'a str) -> Result<U, StrunemixError>
+    where
+        T: StrunemixParsableData<'a, U>,
+        U: StrunemixData<T>
+    {
+        let data = name.add_data(name.normalize(data))
+            .map_err(|source| StrunemixFieldParseError { field: name.get_str(), input: String::from(data), source })?;
+        data.check()?;
+        Ok(data)
+    }
+}
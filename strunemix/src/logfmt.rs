@@ -0,0 +1,104 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+
+use crate::StrunemixParseError;
+
+/// Append a `key=value` pair to a logfmt line, quoting and escaping the value if needed.
+pub(crate) fn write_pair(out: &mut String, key: &str, value: &str) {
+    if !out.is_empty() {
+        out.push(' ');
+    }
+    out.push_str(key);
+    out.push('=');
+
+    let needs_quotes = value.is_empty() || value.chars()
+        .any(|c| c.is_whitespace() || c == '=' || c == '"' || c.is_control());
+
+    if !needs_quotes {
+        out.push_str(value);
+        return;
+    }
+
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Iterator over the `key=value` pairs of a logfmt line.
+///
+/// A key without `=` is yielded with an empty value, quoted values are unescaped.
+pub(crate) struct LogfmtPairs<'a> {
+    rest: &'a str,
+}
+
+impl<'a> LogfmtPairs<'a> {
+    pub(crate) fn new(line: &'a str) -> Self {
+        Self { rest: line }
+    }
+
+    fn quoted(&mut self, key: &str) -> Result<Cow<'a, str>, StrunemixParseError> {
+        let unterminated = || StrunemixParseError::Logfmt(format!("unterminated quoted value for key '{key}'"));
+
+        let mut value = String::new();
+        let mut chars = self.rest.char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(Cow::Owned(value));
+                },
+                '\\' => match chars.next().ok_or_else(unterminated)?.1 {
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    escaped => value.push(escaped),
+                },
+                c => value.push(c),
+            }
+        }
+
+        Err(unterminated())
+    }
+}
+
+impl<'a> Iterator for LogfmtPairs<'a> {
+    type Item = Result<(&'a str, Cow<'a, str>), StrunemixParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rest = self.rest.trim_start();
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let key_end = self.rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(self.rest.len());
+        let key = &self.rest[..key_end];
+        self.rest = &self.rest[key_end..];
+
+        let Some(rest) = self.rest.strip_prefix('=') else {
+            return Some(Ok((key, Cow::Borrowed(""))));
+        };
+        self.rest = rest;
+
+        if let Some(rest) = self.rest.strip_prefix('"') {
+            self.rest = rest;
+            return Some(self.quoted(key).map(|value| (key, value)));
+        }
+
+        let value_end = self.rest.find(char::is_whitespace).unwrap_or(self.rest.len());
+        let value = &self.rest[..value_end];
+        self.rest = &self.rest[value_end..];
+
+        Some(Ok((key, Cow::Borrowed(value))))
+    }
+}
@@ -0,0 +1,115 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::*;
+
+/// How the derived [`FromStr`](core::str::FromStr) of an enum of names matches a string with the names of the fields,
+/// chosen with the `#[strunemix_names(...)]` attribute on the struct.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// assert!(!StrunemixNameMatching::Exact.matches("first_name", "First_Name"));
+/// assert!(StrunemixNameMatching::CaseInsensitive.matches("first_name", "First_Name"));
+/// assert!(StrunemixNameMatching::Normalized.matches("first_name", "firstName"));
+/// assert!(StrunemixNameMatching::Normalized.matches("first_name", "first-name"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrunemixNameMatching {
+    /// The string has to be the name of the field.
+    #[default]
+    Exact,
+    /// The string has to be the name of the field, ignoring the case.
+    CaseInsensitive,
+    /// The string has to be the name of the field, ignoring the case, underscores and dashes,
+    /// so that the `snake_case`, `kebab-case` and `camelCase` spellings all match.
+    Normalized,
+}
+
+impl StrunemixNameMatching {
+    /// Check if the string matches the name of a field.
+    pub fn matches(self, name: &str, data: &str) -> bool {
+        match self {
+            StrunemixNameMatching::Exact => name == data,
+            StrunemixNameMatching::CaseInsensitive => name.chars().flat_map(char::to_lowercase).eq(data.chars().flat_map(char::to_lowercase)),
+            StrunemixNameMatching::Normalized => normalized(name).eq(normalized(data)),
+        }
+    }
+}
+
+/// The characters of a name, in lowercase and without the word separators.
+fn normalized(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+    .filter(|c| *c != '_' && *c != '-')
+    .flat_map(char::to_lowercase)
+}
+
+/// The number of characters to insert, remove or replace to go from a name to another, once normalized.
+fn edit_distance(from: &str, to: &str) -> usize {
+    let to: Vec<char> = normalized(to).collect();
+    let mut row: Vec<usize> = (0..=to.len()).collect();
+
+    for (i, from_char) in normalized(from).enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, to_char) in to.iter().enumerate() {
+            let replaced = diagonal + usize::from(from_char != *to_char);
+            diagonal = row[j + 1];
+            row[j + 1] = replaced.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[to.len()]
+}
+
+/// The names closest to the string, if they are close enough to be a typo.
+fn suggestions(data: &str, names: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    let close: Vec<(usize, &'static str)> = names
+    .map(|name| (edit_distance(data, name), name))
+    .filter(|(distance, name)| *distance <= (data.chars().count().max(name.chars().count()) / 3).max(1))
+    .collect();
+
+    let closest = close.iter().map(|(distance, _)| *distance).min();
+
+    close.into_iter()
+    .filter(|(distance, _)| Some(*distance) == closest)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+impl StrunemixFromError {
+    /// Find the name matching the string, or give the closest names in the error if some are close enough.
+    #[doc(hidden)]
+    pub fn find_name<T: StrunemixName>(data: &str, enum_name: &str, matching: StrunemixNameMatching) -> Result<T, Self> {
+        let names = (0..).map_while(T::from_index);
+
+        if let Some(name) = names.clone().find(|name| matching.matches(name.get_str(), data)) {
+            return Ok(name);
+        }
+
+        let suggestions = suggestions(data, names.map(|name| name.get_str()));
+
+        match suggestions.is_empty() {
+            true => Err(StrunemixFromError::NotAnEnumName(String::from(data), String::from(enum_name))),
+            false => Err(StrunemixFromError::NotAnEnumNameDidYouMean { name: String::from(data), enum_name: String::from(enum_name), suggestions }),
+        }
+    }
+}
+
+/// Display the suggested names of a [`StrunemixFromError::NotAnEnumNameDidYouMean`], if any.
+pub(crate) struct DidYouMean<'a>(pub(crate) &'a [&'static str]);
+
+impl Display for DidYouMean<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if let Some((first, others)) = self.0.split_first() {
+            write!(f, ", did you mean '{first}'")?;
+            for name in others {
+                write!(f, " or '{name}'")?;
+            }
+            write!(f, "?")?;
+        }
+        Ok(())
+    }
+}
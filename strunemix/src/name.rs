@@ -17,9 +17,7 @@ pub trait StrunemixName : Sized + FromStr<Err = StrunemixFromError>
     /// 
     /// assert_eq!(age.get_str(), "age");
     /// ```
-    fn get_str(&self) -> &'static str {
-        panic!("This function should be implemented by the derive macro")
-    }
+    fn get_str(&self) -> &'static str;
 
     /// Get an enum value by its name
     /// 
@@ -36,25 +34,199 @@ pub trait StrunemixName : Sized + FromStr<Err = StrunemixFromError>
     /// 
     /// assert_eq!(age, PersonAttrName::Age);
     fn from_str(name: &str) -> Result<Self, StrunemixFromError> {
-        <Self as std::str::FromStr>::from_str(name)
+        <Self as core::str::FromStr>::from_str(name)
+    }
+
+    /// Get an enum value by the position of its field in the struct
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   age: i32,
+    ///   name: Option<String>,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::from_index(1), Some(PersonAttrName::Name));
+    /// assert_eq!(PersonAttrName::from_index(2), None);
+    /// ```
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Get the position of the field of the enum value in the struct, the inverse of [`StrunemixName::from_index`].
+    /// 
+    /// It is used by [StrunemixForm] to find the field of a name without searching for it,
+    /// so a hand-written implementation has to follow the order of [`StrunemixTrait::as_name_array`].
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   age: i32,
+    ///   name: Option<String>,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Name.index(), 1);
+    /// ```
+    fn index(&self) -> usize;
+
+    /// Apply the transforms declared on the field to a string slice, before it is parsed into data.
+    /// 
+    /// The transforms are declared with `#[strunemix(trim, lowercase, uppercase, collapse_whitespace, transform = ...)]` on the field,
+    /// in the order they have to be applied. [StrunemixForm::set_data_str] calls it before [StrunemixParsableData::add_data], whether it is derived or not.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// fn no_dashes(data: &str) -> String {
+    ///   data.replace('-', "")
+    /// }
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(trim, collapse_whitespace, lowercase)]
+    ///   name: String,
+    ///   #[strunemix(transform = no_dashes)]
+    ///   phone: String,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Name.normalize("  John   DOE "), "john doe");
+    /// assert_eq!(PersonAttrName::Phone.normalize("01-02-03"), "010203");
+    /// ```
+    fn normalize<'a>(&self, data: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(data)
+    }
+
+    /// Get the type of the field, as written in the struct, or an empty string if it is not known by a hand-written implementation.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   age: i32,
+    ///   phones: Vec<Option<String>>,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Age.type_name(), "i32");
+    /// assert_eq!(PersonAttrName::Phones.type_name(), "Vec<Option<String>>");
+    /// ```
+    fn type_name(&self) -> &'static str {
+        ""
+    }
+
+    /// Get the description of the field, taken from its doc comments.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   /// The age of the person,
+    ///   /// in years.
+    ///   age: i32,
+    ///   name: String,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Age.description(), Some("The age of the person,\nin years."));
+    /// assert_eq!(PersonAttrName::Name.description(), None);
+    /// ```
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Get the label of the field, set with `#[strunemix(label = "...")]`, or its name if none was set.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(label = "Age (years)")]
+    ///   age: i32,
+    ///   name: String,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Age.label(), "Age (years)");
+    /// assert_eq!(PersonAttrName::Name.label(), "name");
+    /// ```
+    fn label(&self) -> &'static str {
+        self.get_str()
+    }
+
+    /// Get the placeholder of the field, set with `#[strunemix(placeholder = "...")]`.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(placeholder = "John Doe")]
+    ///   name: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Name.placeholder(), Some("John Doe"));
+    /// assert_eq!(PersonAttrName::Age.placeholder(), None);
+    /// ```
+    fn placeholder(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Get a metadata of the field by its key, set with `#[strunemix(meta(key = "value", ...))]`.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(meta(widget = "slider", unit = "years"))]
+    ///   age: i32,
+    ///   name: String,
+    /// }
+    /// 
+    /// assert_eq!(PersonAttrName::Age.meta("widget"), Some("slider"));
+    /// assert_eq!(PersonAttrName::Age.meta("step"), None);
+    /// assert_eq!(PersonAttrName::Name.meta("widget"), None);
+    /// ```
+    /// 
+    /// A key can only be set once on a field.
+    /// 
+    /// ```rust,compile_fail
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   #[strunemix(meta(widget = "slider"), meta(widget = "text"))]
+    ///   age: i32,
+    /// }
+    /// ```
+    fn meta(&self, key: &str) -> Option<&'static str> {
+        let _ = key;
+        None
     }
 
 }
 
-use std::str::FromStr;
+use alloc::borrow::Cow;
+use core::str::FromStr;
 
 use crate::{error::StrunemixParseError, StrunemixFromError};
 #[cfg(doc)]
-use crate::StrunemixForm;
+use crate::{StrunemixForm, StrunemixTrait};
 
 /// Trait that allow the conversion from a string slice to each of the inner types of an enum of datas.
 /// 
 /// Implement it on a [StrunemixName] generated enum to allow the use of [add_data][StrunemixParsableData] on it.\
 /// It also enables the use of [`StrunemixForm::set_data_str`] on a [StrunemixForm] to set data from a string slice.
 /// 
+/// It can also be derived with the `#[strunemix_parse]` attribute on the struct, see [Strunemix](crate::Strunemix).
+/// 
 /// # Example
 /// 
 /// ```rust
+/// use std::borrow::Cow;
 /// use strunemix::*;
 /// 
 /// #[derive(Strunemix)]
@@ -65,7 +237,7 @@ use crate::StrunemixForm;
 /// }
 /// 
 /// impl StrunemixParsableData<'_, PersonAttrData> for PersonAttrName {
-///   fn add_data(&self, data: &str) -> Result<PersonAttrData, StrunemixParseError> {
+///   fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
 ///     match &self {
 ///       PersonAttrName::Name => Ok(PersonAttrData::Name(Some(data.to_string()))),
 ///       PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?))
@@ -79,7 +251,11 @@ where
 {
     /// Add data from string to an enum of name;
     /// 
+    /// The string is borrowed when it is given as is, and owned when [StrunemixName::normalize] had to build a new one,
+    /// so only borrowed strings can be kept by the data.
+    /// 
     /// ```rust
+    /// # use std::borrow::Cow;
     /// use strunemix::*;
     /// 
     /// #[derive(Strunemix)]
@@ -92,9 +268,9 @@ where
     /// // {Implementation of StrunemixParsableData}
     /// 
     /// let name = PersonAttrName::Name;
-    /// let name_data = name.add_data("John").unwrap();
+    /// let name_data = name.add_data("John".into()).unwrap();
     /// # impl StrunemixParsableData<'_, PersonAttrData> for PersonAttrName {
-    /// #   fn add_data(&self, data: &str) -> Result<PersonAttrData, StrunemixParseError> {
+    /// #   fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
     /// #     match &self {
     /// #       PersonAttrName::Name => Ok(PersonAttrData::Name(data.to_string())),
     /// #       PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?))
@@ -103,5 +279,5 @@ where
     /// # }
     /// assert_eq!(name_data, PersonAttrData::Name("John".to_string()));
     /// ```
-    fn add_data(&self, data: &'a str) -> Result<U, StrunemixParseError>;
+    fn add_data(&self, data: Cow<'a, str>) -> Result<U, StrunemixParseError>;
 }
\ No newline at end of file
@@ -0,0 +1,138 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+
+use crate::*;
+
+/// A change made on a field of a [StrunemixForm], given to the callbacks registered with
+/// [`StrunemixForm::on_change`] and [`StrunemixForm::on_any_change`].
+#[derive(Debug, PartialEq)]
+pub enum Change<'a, U, A> {
+    /// The data of the field was set or removed.
+    Data {
+        old: Option<&'a U>,
+        new: Option<&'a U>,
+    },
+    /// The info of the field was set.
+    Info {
+        old: &'a A,
+        new: &'a A,
+    },
+}
+
+impl<U, A> Clone for Change<'_, U, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U, A> Copy for Change<'_, U, A> {}
+
+type Callback<T, U, A> = Box<dyn FnMut(&T, Change<'_, U, A>) + Send + Sync>;
+
+/// The callbacks registered on a form, with the name of the field they are about if any.
+pub(crate) struct Observers<T, U, A> {
+    callbacks: Vec<(Option<T>, Callback<T, U, A>)>,
+}
+
+impl<T, U, A> Default for Observers<T, U, A> {
+    fn default() -> Self {
+        Self { callbacks: Vec::new() }
+    }
+}
+
+/// The callbacks are not cloned, a cloned form starts without any.
+impl<T, U, A> Clone for Observers<T, U, A> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<T, U, A> Debug for Observers<T, U, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Observers({})", self.callbacks.len())
+    }
+}
+
+impl<T: PartialEq, U, A> Observers<T, U, A> {
+    /// Call the callbacks concerned by a change of the field.
+    pub(crate) fn notify(&mut self, name: &T, change: Change<'_, U, A>) {
+        for (field, callback) in self.callbacks.iter_mut() {
+            if field.as_ref().is_none_or(|field| field == name) {
+                callback(name, change);
+            }
+        }
+    }
+}
+
+impl<T, U, const N: usize, A> StrunemixForm<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    /// Call the function every time a field changes through [`StrunemixForm::set_data`], [`StrunemixForm::set_data_str`],
+    /// [`StrunemixForm::remove_data`] or [`StrunemixForm::set_info`], with the name of the field, the old value and the new one.
+    /// Resetting a field or undoing a change also calls it, changes made in place through the `get_*_mut` methods don't.
+    ///
+    /// Needs the `observe` feature. The callbacks are not kept when the form is cloned, and must be `Send` and `Sync` so the form stays so.
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let ages = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// let mut form = Person::empty_form::<()>();
+    /// let seen = ages.clone();
+    /// form.on_change(PersonAttrName::Age, move |_, change| {
+    ///     if let Change::Data { old, new } = change {
+    ///         seen.lock().unwrap().push((old.cloned(), new.cloned()));
+    ///     }
+    /// })?;
+    ///
+    /// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string()))?;
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42))?;
+    /// form.remove_data(PersonAttrName::Age)?;
+    ///
+    /// assert_eq!(*ages.lock().unwrap(), [
+    ///     (None, Some(PersonAttrData::Age(42))),
+    ///     (Some(PersonAttrData::Age(42)), None),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_change(&mut self, name: impl QueryNameTrait<T>, callback: impl FnMut(&T, Change<'_, U, A>) + Send + Sync + 'static) -> Result<(), StrunemixError> {
+        let name = name.to_attrname()?;
+        self.observers.callbacks.push((Some(name), Box::new(callback)));
+        Ok(())
+    }
+
+    /// Call the function every time any field changes, like [`StrunemixForm::on_change`] does for a single field.
+    ///
+    /// Needs the `observe` feature.
+    pub fn on_any_change(&mut self, callback: impl FnMut(&T, Change<'_, U, A>) + Send + Sync + 'static) {
+        self.observers.callbacks.push((None, Box::new(callback)));
+    }
+
+    /// Tell the observers that the data of a field was replaced.
+    pub(crate) fn notify_data(&mut self, index: usize, previous: &Previous<U>) {
+        if let (Some(Some(name)), Some(field)) = (self.names.get(index), self.fields.get(index)) {
+            self.observers.notify(name, Change::Data { old: field.previous(previous), new: field.data.as_ref() });
+        }
+    }
+
+    /// Remove all the callbacks.
+    ///
+    /// Needs the `observe` feature.
+    pub fn clear_observers(&mut self) {
+        self.observers.callbacks.clear();
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::fmt::Debug;
+
+use crate::*;
+
+/// Object-safe trait implemented automatically on structs with `#[strunemix_reflect]`, to list and access their fields
+/// without knowing the types of their enums of names and data, for example in a `Vec<Box<dyn StrunemixDyn>>`.
+///
+/// The skipped fields are not listed nor accessible.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Debug, Strunemix)]
+/// #[strunemix_parse]
+/// #[strunemix_reflect]
+/// struct Person {
+///   pseudo: String,
+///   age: u8,
+/// }
+///
+/// #[derive(Debug, Strunemix)]
+/// #[strunemix_parse]
+/// #[strunemix_reflect]
+/// struct Pet {
+///   name: String,
+///   age: u8,
+///   vaccinated: bool,
+/// }
+///
+/// # fn main() -> Result<(), StrunemixError> {
+/// let mut records: Vec<Box<dyn StrunemixDyn>> = vec![
+///     Box::new(Person { pseudo: "John".to_string(), age: 42 }),
+///     Box::new(Pet { name: "Rex".to_string(), age: 3, vaccinated: true }),
+/// ];
+///
+/// for record in records.iter_mut() {
+///     record.set_field_str("age", "10")?;
+/// }
+///
+/// assert_eq!(records[1].type_name(), "Pet");
+/// assert_eq!(records[1].field_names(), ["name", "age", "vaccinated"]);
+/// assert_eq!(records[0].get_field::<u8>("age"), Some(&10));
+/// assert_eq!(format!("{:?}", records[1].field_debug("name").unwrap()), "\"Rex\"");
+/// assert!(records[0].get_field_any("vaccinated").is_none());
+/// # Ok(())
+/// # }
+/// ```
+pub trait StrunemixDyn {
+    /// The name of the struct.
+    fn type_name(&self) -> &'static str;
+
+    /// The names of the fields, in the order of the struct.
+    fn field_names(&self) -> &'static [&'static str];
+
+    /// Get a field by its name, to be downcasted to its type.
+    fn get_field_any(&self, name: &str) -> Option<&dyn Any>;
+
+    /// Get a field by its name, to be printed.
+    fn field_debug(&self, name: &str) -> Option<&dyn Debug>;
+
+    /// Set a field by its name, parsed from a string and checked against its constraints like [`StrunemixForm::set_data_str`] does.
+    fn set_field_str(&mut self, name: &str, data: &str) -> Result<(), StrunemixError>;
+
+    /// Get the struct, to be downcasted to its type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Turn the boxed struct into a boxed [`Any`], to be downcasted to its type.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl dyn StrunemixDyn {
+    /// Get a field by its name, if it is of type `V`.
+    pub fn get_field<V: Any>(&self, name: &str) -> Option<&V> {
+        self.get_field_any(name)?.downcast_ref()
+    }
+
+    /// Get the struct, if it is of type `S`.
+    pub fn downcast_ref<S: Any>(&self) -> Option<&S> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Get the boxed struct back, if it is of type `S`.
+    pub fn downcast<S: Any>(self: Box<Self>) -> Result<Box<S>, Box<dyn Any>> {
+        self.into_any().downcast()
+    }
+}
@@ -0,0 +1,105 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::*;
+
+/// The constructors of the structs with `#[strunemix_register]`, collected at link time.
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static STRUNEMIX_REGISTRY: [StrunemixRegistration];
+
+/// Build a registered struct from pairs of field names and string data.
+pub type StrunemixBuildFn = fn(&[(&str, &str)]) -> Result<Box<dyn StrunemixDyn>, StrunemixError>;
+
+/// A struct registered with `#[strunemix_register]`, built by name through the [`StrunemixRegistry`].
+#[derive(Debug, Clone, Copy)]
+pub struct StrunemixRegistration {
+    /// The name of the struct.
+    pub type_name: &'static str,
+    /// The path of the struct, with the module it is declared in, like `my_crate::records::Person`.
+    pub type_path: &'static str,
+    /// Build the struct from an empty form filled with the pairs of field names and string data.
+    pub build: StrunemixBuildFn,
+}
+
+/// Registry of the structs with `#[strunemix_register]`, to build them from their name and string data,
+/// for example when the type of a record is only known at runtime. Needs the `registry` feature.
+///
+/// A struct is found by its path, like `my_crate::records::Person`, or by its name alone if no other registered struct has the same name.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Debug, PartialEq, Strunemix)]
+/// #[strunemix_parse]
+/// #[strunemix_register]
+/// struct Person {
+///   pseudo: String,
+///   age: u8,
+/// }
+///
+/// # fn main() -> Result<(), StrunemixError> {
+/// assert!(StrunemixRegistry::contains("Person"));
+///
+/// let person = StrunemixRegistry::build("Person", [("pseudo", "John"), ("age", "42")])?;
+///
+/// assert_eq!(person.type_name(), "Person");
+/// assert_eq!(person.downcast_ref::<Person>(), Some(&Person { pseudo: "John".to_string(), age: 42 }));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrunemixRegistry;
+
+impl StrunemixRegistry {
+    /// The registered structs, in no particular order.
+    pub fn registrations() -> &'static [StrunemixRegistration] {
+        &STRUNEMIX_REGISTRY
+    }
+
+    /// The names of the registered structs, in no particular order, a name can appear more than once.
+    pub fn type_names() -> impl Iterator<Item = &'static str> {
+        Self::registrations().iter().map(|registration| registration.type_name)
+    }
+
+    /// The paths of the registered structs, in no particular order.
+    pub fn type_paths() -> impl Iterator<Item = &'static str> {
+        Self::registrations().iter().map(|registration| registration.type_path)
+    }
+
+    /// Get the registration of a struct by its path, or by its name if it is the only registered struct with this name.
+    pub fn get(type_name: &str) -> Result<&'static StrunemixRegistration, StrunemixError> {
+        if let Some(registration) = Self::registrations().iter().find(|registration| registration.type_path == type_name) {
+            return Ok(registration);
+        }
+
+        let named: Vec<&'static StrunemixRegistration> = Self::registrations().iter()
+            .filter(|registration| registration.type_name == type_name)
+            .collect();
+
+        match named.as_slice() {
+            [] => Err(StrunemixError::UnknownType(String::from(type_name))),
+            [registration] => Ok(registration),
+            _ => Err(StrunemixError::AmbiguousType {
+                type_name: String::from(type_name),
+                type_paths: named.iter().map(|registration| registration.type_path).collect(),
+            }),
+        }
+    }
+
+    /// Check if a struct is registered with this path or name, even if the name is shared by several structs.
+    pub fn contains(type_name: &str) -> bool {
+        Self::registrations().iter().any(|registration| registration.type_path == type_name || registration.type_name == type_name)
+    }
+
+    /// Build the struct registered with this path or name, from pairs of field names and string data.
+    ///
+    /// The data are parsed and checked like [`StrunemixForm::set_data_str`] does,
+    /// and the struct is built like [`StrunemixTrait::from_form`] does, so every field needs a data.
+    pub fn build<'a>(type_name: &str, pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Box<dyn StrunemixDyn>, StrunemixError> {
+        let registration = Self::get(type_name)?;
+        let pairs: Vec<(&str, &str)> = pairs.into_iter().collect();
+        (registration.build)(&pairs)
+    }
+}
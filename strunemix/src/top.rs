@@ -1,4 +1,6 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::*;
 
@@ -36,9 +38,7 @@ where
     /// 
     /// assert_eq!(Person::as_name_array(), [PersonAttrName::Age, PersonAttrName::Name]);
     /// ```
-    fn as_name_array() -> [T; N] {
-        panic!("This function should be implemented by the derive macro")
-    }
+    fn as_name_array() -> [T; N];
 
     /// Convert a struct into an array of its attributes data, using enums variants of its field names.
     /// Note that the values are not cloned, they are moved, this function consumes the struct.
@@ -62,8 +62,7 @@ where
     /// ```
     fn to_data_array(self) -> [U; N]
     where 
-        Self: Sized
-    {panic!("This function should be implemented by the derive macro")}
+        Self: Sized;
 
     /// Convert an array of attributes data into a struct, using enums variants of its field names.
     /// 
@@ -94,51 +93,415 @@ where
         TryFrom::try_from(data)
     }
 
-    /// Consume the struct into a map-like structure convienient for form handling.
+    /// Convert the data of each field, placed at the position of the field in the struct, into a struct.
+    /// Unlike [`StrunemixTrait::from_attr_data_array`], the data doesn't have to be sorted first, and the derived implementation doesn't allocate.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let slots = [Some(PersonAttrData::Pseudo("John".to_string())), Some(PersonAttrData::Age(42))];
+    /// assert_eq!(Person::from_data_slots(slots).unwrap(), Person {pseudo: "John".to_string(), age: 42});
+    /// 
+    /// let missing = Person::from_data_slots([None, Some(PersonAttrData::Age(42))]).unwrap_err();
+    /// assert_eq!(missing, StrunemixFromError::MissingOrDuplicated {missing: vec!["pseudo"], duplicated: vec![]});
+    /// ```
+    fn from_data_slots(slots: [Option<U>; N]) -> Result<Self, StrunemixFromError>
+    where Self: TryFrom<[U; N], Error = StrunemixFromError>,
+    {
+        let names = Self::as_name_array();
+        let counts = slots.each_ref().map(|slot| slot.is_some() as usize);
+        StrunemixFromError::check_counts(&names.each_ref().map(T::get_str), &counts)?;
+
+        let datas = array_init::from_iter(slots.into_iter().flatten()).ok_or(StrunemixFromError::WrongOrder)?;
+        Self::from_attr_data_array(datas)
+    }
+
+    /// Consume the struct into a form, a structure convienient for form handling.
     /// You have to provide an associated type for the form data. This can be useful to store the form-specific metadata.
     /// The created form will initialize this associated type with a default value, so the [`Default`] trait must be implemented for it.
+    /// Use [`StrunemixTrait::to_form_with`] to give each field its own info.
     fn to_form<A>(self) -> StrunemixForm<T, U, N, A>
     where 
         A: Default,
         T: PartialEq,
         Self: Sized,
     {
-        let names = Self::as_name_array();
-        let datas = self.to_data_array();
+        let names = Self::as_name_array().map(Some);
+        let fields = self.to_data_array().map(|data| Field::new(Some(data), A::default()));
 
-        let res: StrunemixMap<T, U, N, A> = names.into_iter().zip(datas.into_iter())
-        .map(|(name, data)| (name, (Some(data), A::default())))
-        .collect();
+        StrunemixForm::from_fields(names, fields)
+    }
+
+    /// Consume the struct into a form, with the info of each field given by a function of its name and data.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let person = Person {pseudo: "John".to_string(), age: 42};
+    /// let form = person.to_form_with(|name, data| match data {
+    ///     PersonAttrData::Pseudo(pseudo) => format!("{} of {} characters", name.get_str(), pseudo.len()),
+    ///     PersonAttrData::Age(_) => name.get_str().to_string(),
+    /// });
+    /// 
+    /// assert_eq!(form.get_info("pseudo").unwrap(), "pseudo of 4 characters");
+    /// assert_eq!(form.get_info("age").unwrap(), "age");
+    /// ```
+    fn to_form_with<A>(self, mut info: impl FnMut(&T, &U) -> A) -> StrunemixForm<T, U, N, A>
+    where 
+        T: PartialEq + for<'a> From<&'a U>,
+        Self: Sized,
+    {
+        let names = Self::as_name_array().map(Some);
+        let fields = self.to_data_array().map(|data| {
+            let info = info(&data.name(), &data);
+            Field::new(Some(data), info)
+        });
 
-        StrunemixForm::from(res)
+        StrunemixForm::from_fields(names, fields)
     }
 
     /// Consume a form and convert it into a struct.
+    /// The data of each field is checked against the constraints declared on it.
     fn from_form<A>(form: StrunemixForm<T, U, N, A>) -> Result<Self, StrunemixError>
     where
         Self: TryFrom<[U; N], Error = StrunemixFromError>,
         T: PartialEq
     {
-        let datas = form.to_data_array()?;
-        
-        Self::from_attr_data_array(datas).map_err(|e| e.into())
+        if !form.is_complete() {
+            return Err(StrunemixError::IncompleteForm);
+        }
+        form.check_data(&[])?;
+
+        Self::from_data_slots(form.into_data_slots()).map_err(|e| e.into())
+    }
+
+    /// Convert a form into a struct by cloning its data, the form is kept as is.
+    /// The data of each field is checked against the constraints declared on it.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let mut form = Person::empty_form::<()>();
+    /// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string())).unwrap();
+    /// 
+    /// assert!(Person::try_from_form(&form).is_err());
+    /// 
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42)).unwrap();
+    /// assert_eq!(Person::try_from_form(&form).unwrap(), Person {pseudo: "John".to_string(), age: 42});
+    /// ```
+    fn try_from_form<A>(form: &StrunemixForm<T, U, N, A>) -> Result<Self, StrunemixError>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq,
+        U: Clone
+    {
+        if !form.is_complete() {
+            return Err(StrunemixError::IncompleteForm);
+        }
+        form.check_data(&[])?;
+
+        Self::from_data_slots(form.to_data_slots()).map_err(|e| e.into())
+    }
+
+    /// Consume a form and convert it into a struct, giving the form back if it fails, with the names of the fields that had no data.
+    /// The data of each field is checked against the constraints declared on it.
+    /// 
+    /// The form is given back as is when it is incomplete or a constraint is not satisfied, its data is only moved out once they are checked.
+    /// If the conversion from the array of data still fails, which the derived implementation never does, the form is given back without its data.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    ///   height: f32,
+    /// }
+    /// 
+    /// let mut form = Person::empty_form::<()>();
+    /// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string())).unwrap();
+    /// 
+    /// let error = Person::from_form_or_return(form).unwrap_err();
+    /// assert_eq!(error.missing, [PersonAttrName::Age, PersonAttrName::Height]);
+    /// 
+    /// let mut form = error.form;
+    /// assert_eq!(form.get_data(PersonAttrName::Pseudo).unwrap(), Some(&PersonAttrData::Pseudo("John".to_string())));
+    /// 
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42)).unwrap();
+    /// form.set_data(PersonAttrName::Height, PersonAttrData::Height(1.8)).unwrap();
+    /// assert!(Person::from_form_or_return(form).is_ok());
+    /// ```
+    #[allow(clippy::result_large_err)]
+    fn from_form_or_return<A>(form: StrunemixForm<T, U, N, A>) -> Result<Self, StrunemixFormError<T, U, N, A>>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq
+    {
+        from_form_with_fallbacks(form, Vec::new())
+    }
+
+    /// Consume a form and convert it into a struct, the fields without data take the data of the `defaults` struct.
+    /// Only the data of these fields is moved out of `defaults`, the rest of it is dropped.
+    /// The form is given back like with [`StrunemixTrait::from_form_or_return`] if it fails.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let defaults = Person {pseudo: "anonymous".to_string(), age: 18};
+    /// 
+    /// let mut form = Person::empty_form::<()>();
+    /// form.set_data(PersonAttrName::Age, PersonAttrData::Age(42)).unwrap();
+    /// 
+    /// let person = Person::from_form_with(form, defaults).unwrap();
+    /// assert_eq!(person, Person {pseudo: "anonymous".to_string(), age: 42});
+    /// ```
+    #[allow(clippy::result_large_err)]
+    fn from_form_with<A>(form: StrunemixForm<T, U, N, A>, defaults: Self) -> Result<Self, StrunemixFormError<T, U, N, A>>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq
+    {
+        let fallbacks = defaults.to_data_array().into_iter().map(Some).collect();
+        from_form_with_fallbacks(form, fallbacks)
+    }
+
+    /// Consume a form and convert it into a struct, the fields without data take their default data if they have one,
+    /// as declared with `#[strunemix(default)]` or `#[strunemix(default = path)]` and returned by [`StrunemixData::default_data`].
+    /// 
+    /// The form is given back like with [`StrunemixTrait::from_form_or_return`] if it fails, with the names of the fields that had neither data nor default data.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// fn adult() -> i32 {
+    ///   18
+    /// }
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// #[strunemix_derive_data(Debug, PartialEq, Clone)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   #[strunemix(default = adult)]
+    ///   age: i32,
+    ///   #[strunemix(default)]
+    ///   phone: Option<String>,
+    /// }
+    /// 
+    /// let error = Person::from_form_or_default(Person::empty_form::<()>()).unwrap_err();
+    /// assert_eq!(error.missing, [PersonAttrName::Pseudo]);
+    /// 
+    /// let mut form = error.form;
+    /// form.set_data(PersonAttrName::Pseudo, PersonAttrData::Pseudo("John".to_string())).unwrap();
+    /// 
+    /// let person = Person::from_form_or_default(form).unwrap();
+    /// assert_eq!(person, Person {pseudo: "John".to_string(), age: 18, phone: None});
+    /// ```
+    #[allow(clippy::result_large_err)]
+    fn from_form_or_default<A>(form: StrunemixForm<T, U, N, A>) -> Result<Self, StrunemixFormError<T, U, N, A>>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq
+    {
+        let fallbacks = (0..N)
+        .map(|index| T::from_index(index).and_then(|name| U::default_data(&name)))
+        .collect();
+
+        from_form_with_fallbacks(form, fallbacks)
+    }
+
+    /// Consume a form and convert it into a struct, if it is valid for the given [Validator].
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let validator = FormValidator::new()
+    ///     .field(PersonAttrName::Age, |age| match age {
+    ///         PersonAttrData::Age(age) if *age < 0 => Err("must be positive".to_string()),
+    ///         _ => Ok(()),
+    ///     });
+    /// 
+    /// let form = Person {pseudo: "John".to_string(), age: -1}.to_form::<()>();
+    /// 
+    /// match Person::from_form_validated(form, &validator) {
+    ///     Err(StrunemixError::Invalid(report)) => assert_eq!(report.to_string(), "age: must be positive"),
+    ///     _ => panic!("the form should be invalid"),
+    /// }
+    /// ```
+    fn from_form_validated<A>(form: StrunemixForm<T, U, N, A>, validator: &impl Validator<T, U, N, A>) -> Result<Self, StrunemixError>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq
+    {
+        form.validate(validator).map_err(StrunemixError::Invalid)?;
+
+        Self::from_form(form)
     }
 
     /// Create an empty form with default values for the associated type.
+    /// Use [`StrunemixTrait::empty_form_with`] to give each field its own info.
     fn empty_form<A>() -> StrunemixForm<T, U, N, A>
     where 
         A: Default,
         T: PartialEq
+    {
+        Self::empty_form_with(|_| A::default())
+    }
+
+    /// Create an empty form, with the info of each field given by a function of its name.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let form = Person::empty_form_with(|name| name.get_str().len());
+    /// 
+    /// assert_eq!(form.get_info_array(), [&6, &3]);
+    /// ```
+    fn empty_form_with<A>(info: impl FnMut(&T) -> A) -> StrunemixForm<T, U, N, A>
+    where 
+        T: PartialEq
     {
         let names = Self::as_name_array();
+        let fields = names.each_ref().map(info).map(|info| Field::new(None, info));
 
-        let res: StrunemixMap<T, U, N, A> = names.into_iter()
-        .map(|name| (name, (None, A::default())))
-        .collect();
+        StrunemixForm::from_fields(names.map(Some), fields)
+    }
 
-        StrunemixForm::from(res)
+    /// Write the struct as a single logfmt line, like `pseudo=Anna age=25`.
+    /// Values that contain whitespaces, `=` or quotes are quoted and escaped.
+    /// Be sure to implement [StrunemixDisplayableFields] on the struct, or to derive it with `#[strunemix_display]`, to use this method.
+    /// 
+    /// ```rust
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Strunemix)]
+    /// #[strunemix_display]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// let person = Person {pseudo: "Anna".to_string(), age: 25};
+    /// assert_eq!(person.to_logfmt(), "pseudo=Anna age=25");
+    /// ```
+    fn to_logfmt(&self) -> String
+    where
+        Self: StrunemixDisplayableFields<N>,
+    {
+        let mut line = String::new();
+
+        for (name, value) in Self::as_name_array().iter().zip(self.to_data_strings()) {
+            logfmt::write_pair(&mut line, name.get_str(), &value);
+        }
+
+        line
     }
 
+    /// Build a struct from a logfmt line, like `pseudo=Anna age=25`.
+    /// Keys that are not fields of the struct are ignored, the ones that are must all be present.
+    /// Be sure to implement [StrunemixParsableData] on the enum of names to use this method.
+    /// 
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use strunemix::*;
+    /// 
+    /// #[derive(Debug, PartialEq, Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    /// 
+    /// impl StrunemixParsableData<'_, PersonAttrData> for PersonAttrName {
+    ///   fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
+    ///     match self {
+    ///       PersonAttrName::Pseudo => Ok(PersonAttrData::Pseudo(data.to_string())),
+    ///       PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?)),
+    ///     }
+    ///   }
+    /// }
+    /// 
+    /// # fn main() -> Result<(), StrunemixError> {
+    /// let person = Person::from_logfmt(r#"ts=1700000000 pseudo="Anna Lee" age=25"#)?;
+    /// assert_eq!(person, Person {pseudo: "Anna Lee".to_string(), age: 25});
+    /// 
+    /// assert!(Person::from_logfmt("pseudo=Anna").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_logfmt(line: &str) -> Result<Self, StrunemixError>
+    where
+        Self: TryFrom<[U; N], Error = StrunemixFromError>,
+        T: PartialEq + for<'b> StrunemixParsableData<'b, U>,
+    {
+        let mut form = Self::empty_form::<()>();
+        form.set_logfmt(line)?;
+
+        Self::from_form(form)
+    }
+
+}
+
+/// Convert a form into a struct, the fields without data take the one at their position in `fallbacks`.
+/// The form is given back as is if some fields can't be filled or a constraint is not satisfied, and without its data if the conversion fails.
+#[allow(clippy::result_large_err)]
+fn from_form_with_fallbacks<S, T, U, const N: usize, A>(mut form: StrunemixForm<T, U, N, A>, fallbacks: Vec<Option<U>>) -> Result<S, StrunemixFormError<T, U, N, A>>
+where
+    S: StrunemixTrait<T, U, N> + TryFrom<[U; N], Error = StrunemixFromError>,
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    let missing = form.missing_names(&fallbacks);
+
+    if !missing.is_empty() {
+        return Err(StrunemixFormError { form, missing, error: StrunemixError::IncompleteForm });
+    }
+
+    if let Err(error) = form.check_data(&fallbacks) {
+        return Err(StrunemixFormError { form, missing, error: error.into() });
+    }
+
+    let slots = form.take_data_slots_or(fallbacks);
+
+    S::from_data_slots(slots).map_err(|error| StrunemixFormError { form, missing, error: error.into() })
 }
 
 /// Trait implemented automatically to convert a string into an enum name easily.
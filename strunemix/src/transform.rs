@@ -0,0 +1,91 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+
+/// Transforms applied on a string slice before it is parsed into data.
+///
+/// They are used by the `#[strunemix(trim, lowercase, uppercase, collapse_whitespace, transform = ...)]` field attributes,
+/// and only allocate when the input is actually changed.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use strunemix::*;
+///
+/// let data = Cow::Borrowed("  Hello   World ");
+/// let data = StrunemixTransform::trim(data);
+/// let data = StrunemixTransform::collapse_whitespace(data);
+/// let data = StrunemixTransform::lowercase(data);
+///
+/// assert_eq!(data, "hello world");
+/// ```
+pub trait StrunemixTransform: Sized {
+    /// Remove the leading and trailing whitespaces.
+    fn trim(self) -> Self;
+
+    /// Convert all the characters to lowercase.
+    fn lowercase(self) -> Self;
+
+    /// Convert all the characters to uppercase.
+    fn uppercase(self) -> Self;
+
+    /// Replace every run of whitespaces by a single space, leading and trailing ones included.
+    fn collapse_whitespace(self) -> Self;
+
+    /// Apply a custom transform function.
+    fn apply(self, transform: impl FnOnce(&str) -> String) -> Self;
+}
+
+impl StrunemixTransform for Cow<'_, str> {
+    fn trim(self) -> Self {
+        match self {
+            Cow::Borrowed(data) => Cow::Borrowed(data.trim()),
+            Cow::Owned(data) if data.trim().len() == data.len() => Cow::Owned(data),
+            Cow::Owned(data) => Cow::Owned(data.trim().to_string()),
+        }
+    }
+
+    fn lowercase(self) -> Self {
+        if self.chars().any(char::is_uppercase) {
+            Cow::Owned(self.to_lowercase())
+        } else {
+            self
+        }
+    }
+
+    fn uppercase(self) -> Self {
+        if self.chars().any(char::is_lowercase) {
+            Cow::Owned(self.to_uppercase())
+        } else {
+            self
+        }
+    }
+
+    fn collapse_whitespace(self) -> Self {
+        let mut previous_space = false;
+        let collapsed = self.chars().all(|c| {
+            let keep = (c == ' ' && !previous_space) || !c.is_whitespace();
+            previous_space = c.is_whitespace();
+            keep
+        });
+
+        if collapsed {
+            return self;
+        }
+
+        let mut data = String::with_capacity(self.len());
+        previous_space = false;
+        for c in self.chars() {
+            if !c.is_whitespace() {
+                data.push(c);
+            } else if !previous_space {
+                data.push(' ');
+            }
+            previous_space = c.is_whitespace();
+        }
+
+        Cow::Owned(data)
+    }
+
+    fn apply(self, transform: impl FnOnce(&str) -> String) -> Self {
+        Cow::Owned(transform(&self))
+    }
+}
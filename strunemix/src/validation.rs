@@ -0,0 +1,315 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::*;
+
+/// A rule that failed while validating a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The position and the name of the field the error is about, if any.
+    field: Option<(usize, &'static str)>,
+    message: String,
+}
+
+impl ValidationError {
+    /// Create an error for a field of the form.
+    pub fn field<T: StrunemixName>(name: &T, message: impl Into<String>) -> Self {
+        Self { field: Some((name.index(), name.get_str())), message: message.into() }
+    }
+
+    /// Create an error concerning the form as a whole.
+    pub fn form(message: impl Into<String>) -> Self {
+        Self { field: None, message: message.into() }
+    }
+
+    /// The name of the field the error is about, if any.
+    pub fn field_name(&self) -> Option<&'static str> {
+        self.field.map(|(_, name)| name)
+    }
+
+    /// The field the error is about, if any, as a name of the enum.
+    ///
+    /// ```rust
+    /// use strunemix::*;
+    ///
+    /// #[derive(Strunemix)]
+    /// struct Person {
+    ///   pseudo: String,
+    ///   age: i32,
+    /// }
+    ///
+    /// let error = ValidationError::field(&PersonAttrName::Age, "must be positive");
+    ///
+    /// match error.name() {
+    ///     Some(PersonAttrName::Age) => assert_eq!(error.message(), "must be positive"),
+    ///     _ => panic!("the error is about the age"),
+    /// }
+    /// assert_eq!(ValidationError::form("too young").name::<PersonAttrName>(), None);
+    /// ```
+    pub fn name<T: StrunemixName>(&self) -> Option<T> {
+        self.field.and_then(|(index, _)| T::from_index(index))
+    }
+
+    /// The message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.field {
+            Some((_, field)) => write!(f, "{field}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// All the rules that failed while validating a form.
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Strunemix)]
+/// struct Person {
+///   pseudo: String,
+///   age: i32,
+/// }
+///
+/// let mut report = ValidationReport::new();
+/// report.push(ValidationError::field(&PersonAttrName::Age, "must be positive"));
+/// report.push(ValidationError::form("pseudo and age do not match"));
+///
+/// assert!(!report.is_valid());
+/// assert_eq!(report.len(), 2);
+/// assert_eq!(report.for_field(PersonAttrName::Age).count(), 1);
+/// assert_eq!(report.for_field(PersonAttrName::Pseudo).count(), 0);
+/// assert_eq!(report.to_string(), "age: must be positive; pseudo and age do not match");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Create an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error to the report.
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Check that no rule failed.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The number of failed rules.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Check that no rule failed.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// All the errors, in the order the rules were run.
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// The errors about a field.
+    pub fn for_field<T: StrunemixName>(&self, name: T) -> impl Iterator<Item = &ValidationError> {
+        let field = Some((name.index(), name.get_str()));
+        self.errors.iter().filter(move |error| error.field == field)
+    }
+
+    /// The errors concerning the form as a whole.
+    pub fn for_form(&self) -> impl Iterator<Item = &ValidationError> {
+        self.errors.iter().filter(|error| error.field.is_none())
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = ValidationError;
+    type IntoIter = alloc::vec::IntoIter<ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+/// Rules checked on a [StrunemixForm] by [`StrunemixForm::validate`].
+///
+/// It is implemented on [FormValidator] to combine per-field and cross-field rules,
+/// and on any function `Fn(&StrunemixForm, &mut ValidationReport)`.
+pub trait Validator<T, U, const N: usize, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    /// Run the rules on the form and add every failure to the report.
+    fn validate(&self, form: &StrunemixForm<T, U, N, A>, report: &mut ValidationReport);
+}
+
+impl<T, U, const N: usize, A, F> Validator<T, U, N, A> for F
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>,
+    F: Fn(&StrunemixForm<T, U, N, A>, &mut ValidationReport)
+{
+    fn validate(&self, form: &StrunemixForm<T, U, N, A>, report: &mut ValidationReport) {
+        self(form, report)
+    }
+}
+
+type FieldRule<U> = Box<dyn Fn(&U) -> Result<(), String>>;
+type FormRule<T, U, const N: usize, A> = Box<dyn Fn(&StrunemixForm<T, U, N, A>) -> Result<(), ValidationError>>;
+
+/// A set of per-field and cross-field rules to validate a [StrunemixForm].
+///
+/// ```rust
+/// use strunemix::*;
+///
+/// #[derive(Strunemix)]
+/// #[strunemix_derive_data(Debug, PartialEq)]
+/// struct Person {
+///   pseudo: String,
+///   age: i32,
+/// }
+///
+/// let validator = FormValidator::new()
+///     .required(PersonAttrName::Pseudo)
+///     .field(PersonAttrName::Age, |age| match age {
+///         PersonAttrData::Age(age) if *age < 0 => Err("must be positive".to_string()),
+///         _ => Ok(()),
+///     })
+///     .rule(|form| match (form.get_data("pseudo"), form.get_data("age")) {
+///         (Ok(Some(PersonAttrData::Pseudo(pseudo))), Ok(Some(PersonAttrData::Age(age)))) if pseudo.len() > *age as usize => {
+///             Err(ValidationError::form("the pseudo can't be longer than the age"))
+///         },
+///         _ => Ok(()),
+///     });
+///
+/// let mut form = Person::empty_form::<()>();
+/// form.set_data("age", PersonAttrData::Age(-1));
+///
+/// let report = form.validate(&validator).unwrap_err();
+/// assert_eq!(report.to_string(), "pseudo: is required; age: must be positive");
+///
+/// form.set_data("pseudo", PersonAttrData::Pseudo("John".to_string()));
+/// form.set_data("age", PersonAttrData::Age(3));
+///
+/// let report = form.validate(&validator).unwrap_err();
+/// assert_eq!(report.to_string(), "the pseudo can't be longer than the age");
+///
+/// form.set_data("age", PersonAttrData::Age(42));
+/// assert!(form.validate(&validator).is_ok());
+/// ```
+pub struct FormValidator<T, U, const N: usize, A = ()>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    required: Vec<T>,
+    fields: Vec<(T, FieldRule<U>)>,
+    rules: Vec<FormRule<T, U, N, A>>,
+}
+
+impl<T, U, const N: usize, A> Default for FormValidator<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    fn default() -> Self {
+        Self { required: Vec::new(), fields: Vec::new(), rules: Vec::new() }
+    }
+}
+
+impl<T, U, const N: usize, A> FormValidator<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    /// Create a validator without any rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a field to have data.
+    pub fn required(mut self, name: T) -> Self {
+        self.required.push(name);
+        self
+    }
+
+    /// Add a rule checked on the data of a field, when the field has data.
+    pub fn field(mut self, name: T, rule: impl Fn(&U) -> Result<(), String> + 'static) -> Self {
+        self.fields.push((name, Box::new(rule)));
+        self
+    }
+
+    /// Add a rule checked on the whole form, to compare fields together.
+    pub fn rule(mut self, rule: impl Fn(&StrunemixForm<T, U, N, A>) -> Result<(), ValidationError> + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+}
+
+impl<T, U, const N: usize, A> Validator<T, U, N, A> for FormValidator<T, U, N, A>
+where
+    T: StrunemixName + PartialEq,
+    U: StrunemixData<T>
+{
+    fn validate(&self, form: &StrunemixForm<T, U, N, A>, report: &mut ValidationReport) {
+        for name in self.required.iter() {
+            if form.data_of(name).is_none() {
+                report.push(ValidationError::field(name, "is required"));
+            }
+        }
+
+        for (name, rule) in self.fields.iter() {
+            if let Some(data) = form.data_of(name) {
+                if let Err(message) = rule(data) {
+                    report.push(ValidationError::field(name, message));
+                }
+            }
+        }
+
+        for rule in self.rules.iter() {
+            if let Err(error) = rule(form) {
+                report.push(error);
+            }
+        }
+    }
+}
+
+/// Trait to implement on the info type of a [StrunemixForm] to store the validation errors of each field in it,
+/// with [`StrunemixForm::validate_and_store`].
+pub trait ValidationInfo {
+    /// Replace the stored errors of the field, an empty list means the field is valid.
+    fn set_errors(&mut self, errors: Vec<String>);
+}
+
+impl ValidationInfo for Vec<String> {
+    fn set_errors(&mut self, errors: Vec<String>) {
+        *self = errors;
+    }
+}
@@ -0,0 +1,116 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Person {
+    #[strunemix(trim, length(min = 2, max = 8), pattern = "^[a-z]+$")]
+    pseudo: String,
+    #[strunemix(range(min = 0, max = 150))]
+    age: u8,
+    #[strunemix(range(min = -1.5))]
+    balance: f64,
+    #[strunemix(one_of("light", "dark"))]
+    theme: Option<String>,
+    #[strunemix(length(max = 2))]
+    level: Vec<u8>,
+    note: String,
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+pub struct Level {
+    #[strunemix(one_of(1, 2, 3))]
+    level: u8,
+}
+
+fn person() -> Person {
+    Person {
+        pseudo: "john".to_string(),
+        age: 42,
+        balance: 0.0,
+        theme: None,
+        level: vec![1],
+        note: String::new(),
+    }
+}
+
+#[test]
+fn check() {
+    assert!(PersonAttrData::Pseudo("john".to_string()).check().is_ok());
+    assert!(PersonAttrData::Theme(Some("dark".to_string())).check().is_ok());
+    assert!(PersonAttrData::Note("anything at all".to_string()).check().is_ok());
+    assert!(LevelAttrData::Level(2).check().is_ok());
+
+    let error = PersonAttrData::Pseudo("j".to_string()).check().unwrap_err();
+    assert_eq!(error, StrunemixConstraintError { field: "pseudo", constraint: StrunemixConstraint::Length { min: Some("2"), max: Some("8") } });
+
+    let error = PersonAttrData::Pseudo("John".to_string()).check().unwrap_err();
+    assert_eq!(error.constraint, StrunemixConstraint::Pattern("^[a-z]+$"));
+
+    let error = PersonAttrData::Age(151).check().unwrap_err();
+    assert_eq!(error.to_string(), "The field 'age' does not satisfy range(min = 0, max = 150)");
+
+    let error = PersonAttrData::Balance(-2.0).check().unwrap_err();
+    assert_eq!(error.constraint, StrunemixConstraint::Range { min: Some("-1.5"), max: None });
+
+    let error = LevelAttrData::Level(4).check().unwrap_err();
+    assert_eq!(error.constraint, StrunemixConstraint::OneOf(&["1", "2", "3"]));
+
+    let error = PersonAttrData::Level(vec![1, 2, 3]).check().unwrap_err();
+    assert_eq!(error.field, "level");
+    assert!(matches!(error.constraint, StrunemixConstraint::Length { .. }));
+}
+
+#[test]
+fn length_counts_characters() {
+    assert!(PersonAttrData::Pseudo("ééééééé".to_string()).check().is_err_and(|error| matches!(error.constraint, StrunemixConstraint::Pattern(_))));
+    assert_eq!("ééé".length(), 3);
+}
+
+#[test]
+fn set_data() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    let error = form.set_data("age", PersonAttrData::Age(200)).unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(StrunemixConstraintError { field: "age", .. })));
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(42)));
+
+    form.set_data("age", PersonAttrData::Age(150))?;
+
+    Ok(())
+}
+
+#[test]
+fn set_data_str() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    form.set_data_str("pseudo", "  jane ")?;
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("jane".to_string())));
+
+    let error = form.set_data_str("pseudo", "jane doe").unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(StrunemixConstraintError { field: "pseudo", constraint: StrunemixConstraint::Pattern(_) })));
+
+    let error = form.set_data_str("theme", "blue").unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(StrunemixConstraintError { field: "theme", .. })));
+
+    form.set_data_str("theme", "")?;
+
+    Ok(())
+}
+
+#[test]
+fn from_form() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    if let Some(PersonAttrData::Age(age)) = form.get_data_mut("age")? {
+        *age = 151;
+    }
+
+    let error = Person::from_form(form).unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(StrunemixConstraintError { field: "age", .. })));
+
+    assert_eq!(Person::from_form(person().to_form::<()>())?, person());
+
+    Ok(())
+}
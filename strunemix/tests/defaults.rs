@@ -0,0 +1,68 @@
+use strunemix::*;
+
+fn unknown() -> String {
+    "unknown".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+pub struct Person {
+    pseudo: String,
+    #[strunemix(default, range(min = 0))]
+    age: i32,
+    #[strunemix(default = unknown)]
+    city: String,
+    height: f32,
+}
+
+#[test]
+fn default_data() {
+    assert_eq!(PersonAttrData::default_data(&PersonAttrName::Age), Some(PersonAttrData::Age(0)));
+    assert_eq!(PersonAttrData::default_data(&PersonAttrName::City), Some(PersonAttrData::City("unknown".to_string())));
+    assert_eq!(PersonAttrData::default_data(&PersonAttrName::Pseudo), None);
+    assert_eq!(PersonAttrData::default_data(&PersonAttrName::Height), None);
+}
+
+#[test]
+fn from_form_or_default() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+    form.set_data("age", PersonAttrData::Age(30))?;
+
+    let error = Person::from_form_or_default(form).unwrap_err();
+    assert_eq!(error.missing, [PersonAttrName::Pseudo, PersonAttrName::Height]);
+    assert!(matches!(error.error, StrunemixError::IncompleteForm));
+
+    let mut form = error.form;
+    assert_eq!(form.missing_fields(), [&PersonAttrName::Pseudo, &PersonAttrName::City, &PersonAttrName::Height]);
+
+    form.set_data("pseudo", PersonAttrData::Pseudo("Anna".to_string()))?;
+    form.set_data("height", PersonAttrData::Height(1.7))?;
+
+    let person = Person::from_form_or_default(form).map_err(|e| e.error)?;
+    assert_eq!(person, Person {pseudo: "Anna".to_string(), age: 30, city: "unknown".to_string(), height: 1.7});
+
+    Ok(())
+}
+
+#[test]
+fn from_form_with() -> Result<(), StrunemixError> {
+    let defaults = Person {pseudo: "anonymous".to_string(), age: 18, city: "Paris".to_string(), height: 1.6};
+
+    let mut form = Person::empty_form::<()>();
+    form.set_data("city", PersonAttrData::City("Lyon".to_string()))?;
+
+    let person = Person::from_form_with(form, defaults).map_err(|e| e.error)?;
+    assert_eq!(person, Person {pseudo: "anonymous".to_string(), age: 18, city: "Lyon".to_string(), height: 1.6});
+
+    Ok(())
+}
+
+#[test]
+fn fallback_constraints() {
+    let defaults = Person {pseudo: "anonymous".to_string(), age: -1, city: "Paris".to_string(), height: 1.6};
+
+    let error = Person::from_form_with(Person::empty_form::<()>(), defaults).unwrap_err();
+    assert!(error.missing.is_empty());
+    assert!(matches!(error.error, StrunemixError::Constraint(StrunemixConstraintError { field: "age", .. })));
+    assert_eq!(error.form, Person::empty_form::<()>());
+}
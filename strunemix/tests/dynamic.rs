@@ -0,0 +1,135 @@
+use std::error::Error;
+
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_dyn]
+pub struct Reading {
+    sensor: String,
+    value: f32,
+    count: u16,
+    ok: bool,
+    tags: Vec<String>,
+    note: Option<String>,
+}
+
+fn reading() -> Reading {
+    Reading {
+        sensor: "boiler".to_string(),
+        value: 0.1,
+        count: 3,
+        ok: true,
+        tags: vec!["a,b".to_string(), "c".to_string()],
+        note: None,
+    }
+}
+
+#[test]
+fn roundtrip() -> Result<(), StrunemixError> {
+    let form = DynForm::from_form(reading().to_form::<()>());
+    assert!(form.is_complete());
+    assert_eq!(form.get_data("value")?, Some(&DynValue::Float(f64::from(0.1f32))));
+    assert_eq!(form.get_data("note")?, Some(&DynValue::Null));
+
+    let form = form.to_form::<ReadingAttrName, ReadingAttrData, 6, ()>()?;
+    assert_eq!(Reading::from_form(form)?, reading());
+
+    Ok(())
+}
+
+#[test]
+fn partial_form() -> Result<(), StrunemixError> {
+    let schema = DynSchema::of::<ReadingAttrName, ReadingAttrData>();
+    let mut form = DynForm::new(schema);
+
+    form.set_data_str("sensor", "boiler")?;
+    form.set_data_str("count", "70000")?;
+    assert_eq!(form.missing_fields(), ["value", "ok", "tags", "note"]);
+
+    let error = form.to_form::<ReadingAttrName, ReadingAttrData, 6, ()>().unwrap_err();
+    assert!(matches!(error, StrunemixError::DynError(StrunemixDynError::WrongKind { ref field, .. }) if field == "count"));
+    assert_eq!(form.get_data("sensor")?, Some(&DynValue::Str("boiler".to_string())));
+
+    form.set_data("count", DynValue::Int(7))?;
+    let typed = form.to_form::<ReadingAttrName, ReadingAttrData, 6, ()>()?;
+    assert_eq!(typed.get_data(ReadingAttrName::Count)?, Some(&ReadingAttrData::Count(7)));
+    assert_eq!(typed.missing_fields().len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn schema_mismatch() {
+    let schema = DynSchema::of::<ReadingAttrName, ReadingAttrData>().field("count", DynKind::Float);
+    let error = DynForm::new(schema).to_form::<ReadingAttrName, ReadingAttrData, 6, ()>().unwrap_err();
+    assert!(matches!(error, StrunemixError::DynError(StrunemixDynError::SchemaMismatch(ref field)) if field == "count"));
+
+    let schema = DynSchema::of::<ReadingAttrName, ReadingAttrData>().field("unit", DynKind::Str);
+    let error = DynForm::new(schema).to_form::<ReadingAttrName, ReadingAttrData, 6, ()>().unwrap_err();
+    assert_eq!(error.to_string(), "Dynamic form error");
+    assert_eq!(error.source().map(ToString::to_string), Some("The schema doesn't match the one of the struct on the field 'unit'".to_string()));
+}
+
+#[test]
+fn errors() {
+    let schema = DynSchema::new().field("tags", DynKind::List(Box::new(DynKind::Int)));
+    let mut form = DynForm::new(schema);
+
+    let error = form.set_data_str("tags", "1,x").unwrap_err();
+    assert_eq!(error.input(), Some("1,x"));
+
+    let error = form.set_data("tags", DynValue::List(vec![DynValue::Null])).unwrap_err();
+    assert_eq!(error.to_string(), "Dynamic form error");
+    assert_eq!(error.source().map(ToString::to_string), Some("The field 'tags' expects list of int, got List([Null])".to_string()));
+
+    assert!(matches!(form.remove_data("tag"), Err(StrunemixError::DynError(StrunemixDynError::UnknownField(_)))));
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_dyn]
+pub struct Sample {
+    height: f32,
+    #[strunemix(range(max = 100))]
+    size: u64,
+    index: usize,
+    offset: isize,
+}
+
+#[test]
+fn wide_and_narrow_numbers() -> Result<(), StrunemixError> {
+    let mut form = DynForm::new(DynSchema::of::<SampleAttrName, SampleAttrData>());
+    form.set_data_str("height", "1.8")?;
+    form.set_data_str("size", "42")?;
+    form.set_data_str("index", "7")?;
+    form.set_data_str("offset", "-3")?;
+
+    let typed = form.to_form::<SampleAttrName, SampleAttrData, 4, ()>()?;
+    assert_eq!(Sample::from_form(typed)?, Sample { height: 1.8, size: 42, index: 7, offset: -3 });
+
+    form.set_data_str("index", "-7")?;
+    let error = form.to_form::<SampleAttrName, SampleAttrData, 4, ()>().unwrap_err();
+    assert!(matches!(error, StrunemixError::DynError(StrunemixDynError::WrongKind { ref field, .. }) if field == "index"));
+
+    form.set_data_str("index", "7")?;
+    form.set_data_str("height", "1e300")?;
+    assert!(form.to_form::<SampleAttrName, SampleAttrData, 4, ()>().is_err());
+
+    assert_eq!(u64::MAX.into_dyn(), DynValue::Int(i64::MAX));
+    assert_eq!(u64::from_dyn(&DynValue::Int(-1)), None);
+
+    Ok(())
+}
+
+#[test]
+fn constraints_checked() -> Result<(), StrunemixError> {
+    let mut form = DynForm::new(DynSchema::of::<SampleAttrName, SampleAttrData>());
+    form.set_data_str("height", "1.8")?;
+    form.set_data_str("size", "200")?;
+
+    let error = form.to_form::<SampleAttrName, SampleAttrData, 4, ()>().unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(StrunemixConstraintError { field: "size", .. })));
+
+    Ok(())
+}
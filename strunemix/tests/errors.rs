@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::str::FromStr;
+
+use strunemix::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Color(u8, u8, u8);
+
+impl FromStr for Color {
+    type Err = StrunemixParseError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        let hex = data.strip_prefix('#').ok_or_else(|| StrunemixParseError::other("a color starts with '#'"))?;
+        let channel = |range| u8::from_str_radix(hex.get(range).unwrap_or("?"), 16);
+
+        Ok(Color(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+    }
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Theme {
+    name: String,
+    #[strunemix(range(max = 10))]
+    contrast: u8,
+    accent: Color,
+}
+
+fn assert_send_sync<E: Send + Sync + 'static>(_: &E) {}
+
+#[test]
+fn field_context() {
+    let mut form = Theme::empty_form::<()>();
+
+    let error = form.set_data_str("accent", "red").unwrap_err();
+    assert_send_sync(&error);
+    assert_eq!(error.field(), Some("accent"));
+    assert_eq!(error.input(), Some("red"));
+    assert_eq!(error.to_string(), "Field parse error");
+
+    let error = form.set_data_str("contrast", "12").unwrap_err();
+    assert_eq!(error.field(), Some("contrast"));
+    assert_eq!(error.input(), None);
+
+    let error = form.set_data_str("theme", "dark").unwrap_err();
+    assert_eq!(error.field(), None);
+}
+
+#[test]
+fn source_chain() {
+    let mut form = Theme::empty_form::<()>();
+    let error = form.set_data_str("accent", "#00ffzz").unwrap_err();
+
+    let mut chain = Vec::new();
+    let mut source: Option<&(dyn Error + 'static)> = Some(&error);
+    while let Some(error) = source {
+        chain.push(error.to_string());
+        source = error.source();
+    }
+
+    assert_eq!(chain.len(), 4);
+    assert_eq!(chain[1], "The field 'accent' can't be parsed from \"#00ffzz\"");
+    assert_eq!(chain[3], "invalid digit found in string");
+
+    let boxed: Box<dyn Error + Send + Sync> = error.into();
+    assert!(boxed.downcast_ref::<StrunemixError>().is_some());
+}
+
+#[test]
+fn other_source_chain() {
+    let mut form = Theme::empty_form::<()>();
+    let error = form.set_data_str("accent", "red").unwrap_err();
+
+    let mut chain = Vec::new();
+    let mut source: Option<&(dyn Error + 'static)> = Some(&error);
+    while let Some(error) = source {
+        chain.push(error.to_string());
+        source = error.source();
+    }
+
+    assert_eq!(chain, [
+        "Field parse error",
+        "The field 'accent' can't be parsed from \"red\"",
+        "Invalid data",
+        "a color starts with '#'",
+    ]);
+}
@@ -102,13 +102,13 @@ fn form_empty() -> Result<(), StrunemixError> {
     assert_eq!(person.get_data(PersonAttrName::Age)?, None);
 
     let finished = person.is_complete();
-    assert_eq!(finished, false);
+    assert!(!finished);
 
     let failed = Person::from_form(person).unwrap_err();
 
     match failed {
         StrunemixError::IncompleteForm => (),
-        _ => assert!(false)
+        _ => panic!("expected an incomplete form error")
     }
 
     Ok(())
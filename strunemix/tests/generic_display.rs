@@ -0,0 +1,29 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Eq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_display]
+pub struct Person<'a, A>{
+    name: &'a str,
+    age: i32,
+    note: A
+}
+
+#[test]
+fn to_data_string() {
+    assert_eq!(PersonAttrData::<f32>::Name("John").to_data_string(), "John");
+    assert_eq!(PersonAttrData::<f32>::Age(42).to_data_string(), "42");
+    assert_eq!(PersonAttrData::<f32>::Note(1.5).to_data_string(), "1.5");
+}
+
+#[test]
+fn form() -> Result<(), StrunemixError> {
+    let person = Person {name: "John", age: 42, note: 'n'};
+
+    let form = person.to_form::<()>();
+
+    assert_eq!(form.get_data_str(PersonAttrName::Name)?, Some("John".to_string()));
+    assert_eq!(form.get_data_str("note")?, Some("n".to_string()));
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use strunemix::*;
 
 #[derive(Debug, PartialEq, Eq, Strunemix)]
@@ -11,10 +12,13 @@ pub struct Person<'a, A>{
 impl<'a, 'b> StrunemixParsableData<'a, PersonAttrData::<'b, String>> for PersonAttrName
     where 'a: 'b
 {
-    fn add_data(&self, data: &'a str) -> Result<PersonAttrData::<'b, String>, StrunemixParseError> {
+    fn add_data(&self, data: Cow<'a, str>) -> Result<PersonAttrData::<'b, String>, StrunemixParseError> {
         match self {
-            PersonAttrName::Name => Ok(PersonAttrData::Name(Some(data))),
-            PersonAttrName::Age => Ok(data.parse().map(|age| PersonAttrData::Age(age))?),
+            PersonAttrName::Name => match data {
+                Cow::Borrowed(data) => Ok(PersonAttrData::Name(Some(data))),
+                Cow::Owned(_) => Err(StrunemixParseError::other("the name can't be borrowed")),
+            },
+            PersonAttrName::Age => Ok(data.parse().map(PersonAttrData::Age)?),
             PersonAttrName::Note => Ok(PersonAttrData::Note(data.to_string()))
         }
     }
@@ -23,7 +27,7 @@ impl<'a, 'b> StrunemixParsableData<'a, PersonAttrData::<'b, String>> for PersonA
 #[test]
 fn from_name(){
     let name = PersonAttrName::Name;
-    let name_data = name.add_data("John").unwrap();
+    let name_data = name.add_data("John".into()).unwrap();
 
     assert_eq!(name_data, PersonAttrData::Name(Some("John")));
 }
@@ -32,7 +36,7 @@ fn from_name(){
 fn from_string(){
     let name = "name";
     let data = "John";
-    let name_data = PersonAttrName::from_str(name).unwrap().add_data(data).unwrap();
+    let name_data = PersonAttrName::from_str(name).unwrap().add_data(data.into()).unwrap();
 
     assert_eq!(name_data, PersonAttrData::Name(Some("John")));
 }
\ No newline at end of file
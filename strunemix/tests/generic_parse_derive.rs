@@ -0,0 +1,37 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Eq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Person<'a, A>{
+    name: Option<&'a str>,
+    age: i32,
+    notes: Vec<A>
+}
+
+#[test]
+fn from_name() -> Result<(), StrunemixParseError> {
+    assert_eq!(PersonAttrName::Name.add_data("John".into())?, PersonAttrData::<char>::Name(Some("John")));
+    assert_eq!(PersonAttrName::Name.add_data("".into())?, PersonAttrData::<char>::Name(None));
+    assert_eq!(PersonAttrName::Notes.add_data("a,b".into())?, PersonAttrData::Notes(vec!['a', 'b']));
+
+    let error: Result<PersonAttrData<char>, _> = PersonAttrName::Notes.add_data("ab".into());
+    assert!(matches!(error, Err(StrunemixParseError::ParseCharError(_))));
+
+    Ok(())
+}
+
+#[test]
+fn form() -> Result<(), StrunemixError> {
+    let mut form = Person::<u8>::empty_form::<()>();
+
+    form.set_data_str("name", "Jane")?;
+    form.set_data_str("age", "43")?;
+    form.set_data_str("notes", "1,2,3")?;
+
+    let person = Person::from_form(form)?;
+
+    assert_eq!(person, Person {name: Some("Jane"), age: 43, notes: vec![1, 2, 3]});
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ pub struct Person1<'a, A>{
 }
 
 #[test]
-fn rebuild_normal<'a>() {
+fn rebuild_normal() {
     let expected = Person1 {name: Some("John"), age: 42, note: "note".to_string()};
 
     let name = Person1AttrData::<String>::Name(Some("John"));
@@ -0,0 +1,169 @@
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_parse]
+pub struct Person {
+    pseudo: String,
+    age: i32,
+}
+
+fn person() -> Person {
+    Person {pseudo: "Anna".to_string(), age: 25}
+}
+
+#[test]
+fn undo_redo() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("age", "26")?;
+    form.set_data_str("age", "27")?;
+    form.remove_data("pseudo")?;
+
+    assert!(form.undo());
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("Anna".to_string())));
+
+    assert!(form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(26)));
+
+    assert!(form.redo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(27)));
+
+    assert!(form.undo());
+    assert!(form.undo());
+    assert!(!form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    assert!(form.touched_fields().is_empty());
+
+    assert!(form.redo());
+    assert!(form.redo());
+    assert!(form.redo());
+    assert!(!form.redo());
+    assert_eq!(form.get_data("pseudo")?, None);
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(27)));
+
+    Ok(())
+}
+
+#[test]
+fn new_edit_clears_redo() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("age", "26")?;
+    assert!(form.undo());
+    assert!(form.can_redo());
+
+    form.set_data_str("pseudo", "Lee")?;
+    assert!(!form.can_redo());
+    assert!(!form.redo());
+
+    Ok(())
+}
+
+#[test]
+fn failed_edits_are_not_recorded() {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    assert!(form.set_data_str("age", "old").is_err());
+    assert!(!form.can_undo());
+}
+
+#[test]
+fn bounded_capacity() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(2);
+
+    for age in 26..30 {
+        form.set_data("age", PersonAttrData::Age(age))?;
+    }
+
+    assert!(form.undo());
+    assert!(form.undo());
+    assert!(!form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(27)));
+
+    Ok(())
+}
+
+#[test]
+fn groups() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("age", "30")?;
+
+    form.group(|form| -> Result<(), StrunemixError> {
+        form.set_data_str("pseudo", "Lee")?;
+        form.group(|form| form.set_data_str("age", "31"))?;
+        form.remove_data("pseudo")
+    })?;
+
+    assert!(form.undo());
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("Anna".to_string())));
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(30)));
+
+    assert!(form.redo());
+    assert_eq!(form.get_data("pseudo")?, None);
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(31)));
+
+    assert!(form.undo());
+    assert!(form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    assert!(!form.can_undo());
+
+    Ok(())
+}
+
+#[test]
+fn reset_is_recorded() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("age", "26")?;
+    form.reset("age")?;
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+
+    assert!(form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(26)));
+    assert!(form.is_dirty());
+
+    form.reset("pseudo")?;
+    assert!(form.undo());
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    form.set_data_str("age", "26")?;
+    assert!(!form.can_undo());
+    assert!(!form.undo());
+
+    form.enable_history(10);
+    form.set_data_str("age", "27")?;
+    form.disable_history();
+    assert!(!form.undo());
+
+    Ok(())
+}
+
+#[test]
+fn equality_ignores_history() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("age", "26")?;
+    form.set_data_str("age", "25")?;
+    assert_eq!(form, person().to_form::<()>());
+
+    assert!(form.undo());
+    assert_ne!(form, person().to_form::<()>());
+
+    Ok(())
+}
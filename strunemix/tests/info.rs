@@ -0,0 +1,84 @@
+use strunemix::*;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bounds {
+    min: i64,
+    max: i64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MaxLength(usize);
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_info(Debug, Clone, PartialEq)]
+pub struct Product<V> {
+    #[strunemix(info = MaxLength)]
+    label: String,
+    #[strunemix(info = Bounds)]
+    stock: i64,
+    #[strunemix(info = Bounds)]
+    price: V,
+    available: bool,
+}
+
+fn product() -> Product<i64> {
+    Product { label: "Chair".to_string(), stock: 12, price: 40, available: true }
+}
+
+#[test]
+fn empty_form_with_info() {
+    let form = Product::<i64>::empty_form_with(|name| name.get_str().len());
+    assert_eq!(form.get_info_array(), [&5, &5, &5, &9]);
+
+    let form = Product::<i64>::empty_form_with(ProductAttrInfo::default_for);
+    assert_eq!(form.get_info("label").unwrap(), &ProductAttrInfo::Label(MaxLength(0)));
+    assert_eq!(form.get_info("available").unwrap(), &ProductAttrInfo::Available(()));
+}
+
+#[test]
+fn to_form_with_info() {
+    let form = product().to_form_with(|name, data| match data {
+        ProductAttrData::Label(label) => ProductAttrInfo::Label(MaxLength(label.len() * 2)),
+        ProductAttrData::Stock(stock) => ProductAttrInfo::Stock(Bounds { min: 0, max: *stock }),
+        _ => ProductAttrInfo::default_for(name),
+    });
+
+    let infos: Vec<_> = form.get_info_array().into_iter().cloned().collect();
+    assert_eq!(infos, [
+        ProductAttrInfo::Label(MaxLength(10)),
+        ProductAttrInfo::Stock(Bounds { min: 0, max: 12 }),
+        ProductAttrInfo::Price(Bounds::default()),
+        ProductAttrInfo::Available(()),
+    ]);
+
+    let names: Vec<_> = infos.iter().map(|info| info.name()).collect();
+    assert_eq!(names, Product::<i64>::as_name_array());
+
+    assert_eq!(Product::from_form(form).unwrap(), product());
+}
+
+#[test]
+fn check_data_against_info() {
+    let mut form = product().to_form_with(|name, _| ProductAttrInfo::default_for(name));
+    form.set_info("price", ProductAttrInfo::Price(Bounds { min: 1, max: 30 })).unwrap();
+
+    let too_expensive = form.iter().any(|(_, data, info)| match (info, data) {
+        (ProductAttrInfo::Price(bounds), Some(ProductAttrData::Price(price))) => !(bounds.min..=bounds.max).contains(price),
+        _ => false,
+    });
+    assert!(too_expensive);
+}
+
+#[test]
+fn checked_info() {
+    let mut form = product().to_form_with(|name, _| ProductAttrInfo::default_for(name));
+
+    form.set_info_checked("stock", ProductAttrInfo::Stock(Bounds { min: 0, max: 100 })).unwrap();
+    assert_eq!(form.get_info("stock").unwrap(), &ProductAttrInfo::Stock(Bounds { min: 0, max: 100 }));
+
+    let error = form.set_info_checked("price", ProductAttrInfo::Label(MaxLength(8))).unwrap_err();
+    assert!(matches!(error, StrunemixError::WrongInfo { field: "price", info: "label" }));
+    assert_eq!(error.field(), Some("price"));
+    assert_eq!(form.get_info("price").unwrap(), &ProductAttrInfo::Price(Bounds::default()));
+}
@@ -0,0 +1,122 @@
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+pub struct Person {
+    pseudo: String,
+    age: i32,
+    phone: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+pub struct Counter {
+    #[strunemix(range(min = 0))]
+    count: i32,
+    #[strunemix(range(min = 1))]
+    step: i32,
+}
+
+fn person() -> Person {
+    Person {pseudo: "Anna".to_string(), age: 25, phone: None}
+}
+
+#[test]
+fn iter() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<u8>();
+    form.remove_data("pseudo")?;
+    form.set_info("age", 3)?;
+
+    let fields: Vec<_> = form.iter().collect();
+    assert_eq!(fields, [
+        (PersonAttrName::Pseudo, None, &0),
+        (PersonAttrName::Age, Some(&PersonAttrData::Age(25)), &3),
+        (PersonAttrName::Phone, Some(&PersonAttrData::Phone(None)), &0),
+    ]);
+
+    let names: Vec<_> = (&form).into_iter().map(|(name, _, _)| name).collect();
+    assert_eq!(names, Person::as_name_array());
+    assert_eq!(form.iter().len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn iter_mut() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<u8>();
+
+    for (name, mut data, info) in &mut form {
+        if let Some(PersonAttrData::Age(age)) = data.as_deref_mut() {
+            *age += 1;
+        }
+        *info = name.get_str().len() as u8;
+    }
+
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(26)));
+    assert_eq!(form.get_info_array(), [&6, &3, &5]);
+    assert!(form.is_dirty());
+
+    Ok(())
+}
+
+#[test]
+fn into_iter() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<u8>();
+    form.remove_data("phone")?;
+
+    let fields: Vec<_> = form.into_iter().collect();
+    assert_eq!(fields, [
+        (PersonAttrName::Pseudo, Some(PersonAttrData::Pseudo("Anna".to_string())), 0),
+        (PersonAttrName::Age, Some(PersonAttrData::Age(25)), 0),
+        (PersonAttrName::Phone, None, 0),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn filled_and_missing() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+    assert_eq!(form.len_filled(), 0);
+    assert_eq!(form.missing_fields().len(), 3);
+    assert!(form.filled_fields().is_empty());
+
+    form.set_data("phone", PersonAttrData::Phone(None))?;
+    assert_eq!(form.filled_fields(), [&PersonAttrName::Phone]);
+    assert_eq!(form.missing_fields(), [&PersonAttrName::Pseudo, &PersonAttrName::Age]);
+    assert_eq!(form.len_filled(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn from_iter_and_extend() -> Result<(), StrunemixError> {
+    let datas = [PersonAttrData::Age(30), PersonAttrData::Phone(None), PersonAttrData::Age(31)];
+
+    let mut form: StrunemixForm<_, _, 3> = datas.into_iter().collect();
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(31)));
+    assert_eq!(form.missing_fields(), [&PersonAttrName::Pseudo]);
+    assert!(form.touched_fields().is_empty());
+
+    form.extend([PersonAttrData::Pseudo("Lee".to_string())]);
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Pseudo]);
+
+    assert_eq!(Person::from_form(form)?, Person {pseudo: "Lee".to_string(), age: 31, phone: None});
+
+    let form: StrunemixForm<_, _, 3> = person().to_data_array().into_iter().collect();
+    assert_eq!(form, person().to_form::<()>());
+
+    Ok(())
+}
+
+#[test]
+fn from_iter_and_extend_check_constraints() -> Result<(), StrunemixError> {
+    let mut form: StrunemixForm<_, _, 2> = [CounterAttrData::Count(-1), CounterAttrData::Step(2)].into_iter().collect();
+    assert_eq!(form.missing_fields(), [&CounterAttrName::Count]);
+
+    form.extend([CounterAttrData::Count(3), CounterAttrData::Step(0)]);
+    assert_eq!(form.get_data("count")?, Some(&CounterAttrData::Count(3)));
+    assert_eq!(form.get_data("step")?, Some(&CounterAttrData::Step(2)));
+
+    Ok(())
+}
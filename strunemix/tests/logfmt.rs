@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_display]
+pub struct Person {
+    pseudo: String,
+    age: i32,
+    bio: String,
+}
+
+impl<'a> StrunemixParsableData<'a, PersonAttrData> for PersonAttrName {
+    fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
+        match &self {
+            PersonAttrName::Pseudo => Ok(PersonAttrData::Pseudo(data.to_string())),
+            PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?)),
+            PersonAttrName::Bio => Ok(PersonAttrData::Bio(data.to_string())),
+        }
+    }
+}
+
+#[test]
+fn encode() {
+    let person = Person {pseudo: "Anna".to_string(), age: 25, bio: "likes \"cats\" and a=b".to_string()};
+
+    assert_eq!(person.to_logfmt(), r#"pseudo=Anna age=25 bio="likes \"cats\" and a=b""#);
+}
+
+#[test]
+fn encode_empty_value() {
+    let person = Person {pseudo: "Anna".to_string(), age: 25, bio: String::new()};
+
+    assert_eq!(person.to_logfmt(), r#"pseudo=Anna age=25 bio="""#);
+}
+
+#[test]
+fn round_trip() -> Result<(), StrunemixError> {
+    let person = Person {pseudo: "Anna Lee".to_string(), age: -3, bio: "line\none\ttab \\ slash".to_string()};
+
+    let line = person.to_logfmt();
+    assert!(!line.contains('\n'));
+
+    assert_eq!(Person::from_logfmt(&line)?, person);
+
+    Ok(())
+}
+
+#[test]
+fn round_trip_unicode_whitespace() -> Result<(), StrunemixError> {
+    let person = Person {pseudo: "Anna\u{a0}Lee".to_string(), age: 25, bio: "em\u{2003}space".to_string()};
+
+    let line = person.to_logfmt();
+    assert_eq!(line, "pseudo=\"Anna\u{a0}Lee\" age=25 bio=\"em\u{2003}space\"");
+
+    assert_eq!(Person::from_logfmt(&line)?, person);
+
+    Ok(())
+}
+
+#[test]
+fn decode_unknown_keys() -> Result<(), StrunemixError> {
+    let person = Person::from_logfmt("level=info  pseudo=Anna msg=\"hello world\" age=25 verbose bio=")?;
+
+    assert_eq!(person, Person {pseudo: "Anna".to_string(), age: 25, bio: String::new()});
+
+    Ok(())
+}
+
+#[test]
+fn decode_errors() {
+    let missing = Person::from_logfmt("pseudo=Anna age=25").unwrap_err();
+    assert!(matches!(missing, StrunemixError::IncompleteForm));
+
+    let unterminated = Person::from_logfmt("pseudo=\"Anna age=25 bio=x").unwrap_err();
+    assert!(matches!(unterminated, StrunemixError::ParseError(StrunemixParseError::Logfmt(_))));
+
+    let bad_int = Person::from_logfmt("pseudo=Anna age=old bio=x").unwrap_err();
+    assert!(matches!(&bad_int, StrunemixError::FieldParseError(StrunemixFieldParseError { field: "age", input, source: StrunemixParseError::ParseIntError(_) }) if input == "old"));
+}
+
+#[test]
+fn form() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    form.set_logfmt("age=25")?;
+    assert_eq!(form.to_logfmt(), "age=25");
+
+    form.set_logfmt("pseudo=Anna bio=none age=26")?;
+    assert_eq!(form.to_logfmt(), "pseudo=Anna age=26 bio=none");
+
+    Ok(())
+}
+
+#[test]
+fn form_untouched_on_error() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+    form.set_logfmt("age=25")?;
+
+    assert!(form.set_logfmt("pseudo=Anna age=old bio=x").is_err());
+    assert!(form.set_logfmt("pseudo=Anna bio=\"x").is_err());
+    assert_eq!(form.to_logfmt(), "age=25");
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use strunemix::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PointAttrName {
+    X,
+    Y,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointAttrData {
+    X(i32),
+    Y(i32),
+}
+
+impl FromStr for PointAttrName {
+    type Err = StrunemixFromError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "x" => Ok(PointAttrName::X),
+            "y" => Ok(PointAttrName::Y),
+            _ => Err(StrunemixFromError::NotAnEnumName(name.to_string(), "PointAttrName".to_string())),
+        }
+    }
+}
+
+impl StrunemixName for PointAttrName {
+    fn get_str(&self) -> &'static str {
+        match self {
+            PointAttrName::X => "x",
+            PointAttrName::Y => "y",
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        [PointAttrName::X, PointAttrName::Y].get(index).copied()
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl From<&PointAttrData> for PointAttrName {
+    fn from(data: &PointAttrData) -> Self {
+        match data {
+            PointAttrData::X(_) => PointAttrName::X,
+            PointAttrData::Y(_) => PointAttrName::Y,
+        }
+    }
+}
+
+impl StrunemixData<PointAttrName> for PointAttrData {}
+
+impl StrunemixParsableData<'_, PointAttrData> for PointAttrName {
+    fn add_data(&self, data: Cow<str>) -> Result<PointAttrData, StrunemixParseError> {
+        match self {
+            PointAttrName::X => Ok(PointAttrData::X(data.parse()?)),
+            PointAttrName::Y => Ok(PointAttrData::Y(data.parse()?)),
+        }
+    }
+}
+
+impl TryFrom<[PointAttrData; 2]> for Point {
+    type Error = StrunemixFromError;
+
+    fn try_from(data: [PointAttrData; 2]) -> Result<Self, Self::Error> {
+        match data {
+            [PointAttrData::X(x), PointAttrData::Y(y)] => Ok(Point {x, y}),
+            _ => Err(StrunemixFromError::WrongOrder),
+        }
+    }
+}
+
+impl StrunemixTrait<PointAttrName, PointAttrData, 2> for Point {
+    fn as_name_array() -> [PointAttrName; 2] {
+        [PointAttrName::X, PointAttrName::Y]
+    }
+
+    fn to_data_array(self) -> [PointAttrData; 2] {
+        [PointAttrData::X(self.x), PointAttrData::Y(self.y)]
+    }
+}
+
+/// A struct whose hand-written implementation gives the same name twice, so its forms miss a field.
+#[derive(Debug, PartialEq)]
+pub struct Broken {
+    x: i32,
+    y: i32,
+}
+
+impl TryFrom<[PointAttrData; 2]> for Broken {
+    type Error = StrunemixFromError;
+
+    fn try_from(data: [PointAttrData; 2]) -> Result<Self, Self::Error> {
+        Point::try_from(data).map(|Point {x, y}| Broken {x, y})
+    }
+}
+
+impl StrunemixTrait<PointAttrName, PointAttrData, 2> for Broken {
+    fn as_name_array() -> [PointAttrName; 2] {
+        [PointAttrName::X, PointAttrName::X]
+    }
+
+    fn to_data_array(self) -> [PointAttrData; 2] {
+        [PointAttrData::X(self.x), PointAttrData::Y(self.y)]
+    }
+}
+
+/// A struct whose hand-written conversion refuses some complete arrays of data.
+#[derive(Debug, PartialEq)]
+pub struct Diagonal {
+    x: i32,
+}
+
+impl TryFrom<[PointAttrData; 2]> for Diagonal {
+    type Error = StrunemixFromError;
+
+    fn try_from(data: [PointAttrData; 2]) -> Result<Self, Self::Error> {
+        match Point::try_from(data)? {
+            Point {x, y} if x == y => Ok(Diagonal {x}),
+            _ => Err(StrunemixFromError::WrongOrder),
+        }
+    }
+}
+
+impl StrunemixTrait<PointAttrName, PointAttrData, 2> for Diagonal {
+    fn as_name_array() -> [PointAttrName; 2] {
+        [PointAttrName::X, PointAttrName::Y]
+    }
+
+    fn to_data_array(self) -> [PointAttrData; 2] {
+        [PointAttrData::X(self.x), PointAttrData::Y(self.x)]
+    }
+}
+
+#[test]
+fn hand_written_form() -> Result<(), StrunemixError> {
+    let mut form = Point {x: 1, y: 2}.to_form::<()>();
+
+    form.set_data_str("y", "5")?;
+    assert_eq!(form.get_data("y")?, Some(&PointAttrData::Y(5)));
+    assert!(form.get_data("z").is_err());
+
+    assert_eq!(Point::from_form(form)?, Point {x: 1, y: 5});
+
+    let wrong = Point::from_attr_data_array([PointAttrData::Y(1), PointAttrData::X(2)]);
+    assert_eq!(wrong.unwrap_err(), StrunemixFromError::WrongOrder);
+
+    Ok(())
+}
+
+#[test]
+fn missing_field_errors() {
+    let mut form = Broken {x: 1, y: 2}.to_form::<()>();
+
+    assert!(form.get_data("x").is_ok());
+    assert!(matches!(form.get_data("y"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.get_info("y"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.get_data_mut("y"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.get_info_mut("y"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.set_data("y", PointAttrData::Y(3)), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.set_data_str("y", "3"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.remove_data("y"), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.set_info("y", ()), Err(StrunemixError::MissingField("y"))));
+    assert!(matches!(form.reset("y"), Err(StrunemixError::MissingField("y"))));
+
+    form.extend([PointAttrData::Y(3)]);
+    assert!(!form.is_dirty());
+
+    assert!(matches!(form.clone().to_data_array(), Err(StrunemixError::IncompleteForm)));
+    assert!(matches!(Broken::from_form(form), Err(StrunemixError::IncompleteForm)));
+}
+
+#[test]
+fn fields_at_the_index_of_their_name() -> Result<(), StrunemixError> {
+    let mut form = Point::empty_form::<()>();
+    form.set_data(PointAttrName::Y, PointAttrData::Y(2))?;
+    form.set_data_str("x", "1")?;
+
+    assert_eq!(form.filled_fields(), [&PointAttrName::X, &PointAttrName::Y]);
+    assert_eq!(form.to_data_array()?, [PointAttrData::X(1), PointAttrData::Y(2)]);
+
+    let form = Broken {x: 1, y: 2}.to_form::<()>();
+    assert_eq!(form.get_data(PointAttrName::X)?, Some(&PointAttrData::X(1)));
+    assert!(!form.is_complete());
+
+    Ok(())
+}
+
+#[test]
+fn failed_conversion_gives_the_form_back() {
+    let form = Point {x: 1, y: 2}.to_form::<()>();
+
+    let error = Diagonal::from_form_or_return(form).unwrap_err();
+    assert!(matches!(error.error, StrunemixError::ConversionError(StrunemixFromError::WrongOrder)));
+    assert!(error.missing.is_empty());
+
+    let mut form = error.form;
+    assert_eq!(form.get_data("x").unwrap(), None);
+    assert_eq!(form.get_data("y").unwrap(), None);
+
+    form.set_data("x", PointAttrData::X(1)).unwrap();
+    form.set_data("y", PointAttrData::Y(1)).unwrap();
+    assert_eq!(Diagonal::from_form_or_return(form).unwrap(), Diagonal {x: 1});
+}
@@ -0,0 +1,45 @@
+use strunemix::*;
+
+#[derive(Strunemix)]
+pub struct Profile<'a, V> {
+    /// The name shown to the other users.
+    ///
+    /// It has to be unique.
+    #[strunemix(label = "Pseudo", placeholder = "john_doe", meta(widget = "text", autocomplete = "username"))]
+    pseudo: &'a str,
+    #[strunemix(meta(widget = "map"))]
+    position: (f32, Option<f32>),
+    /// Raw bytes of the avatar
+    avatar: std::vec::Vec<[u8; 4]>,
+    #[strunemix(skip)]
+    _secret: (),
+    value: V,
+}
+
+#[test]
+fn type_names() {
+    let names: Vec<_> = (0..).map_while(ProfileAttrName::from_index).map(|name| name.type_name()).collect();
+    assert_eq!(names, ["&'a str", "(f32, Option<f32>)", "std::vec::Vec<[u8; 4]>", "V"]);
+}
+
+#[test]
+fn descriptions_and_labels() {
+    assert_eq!(ProfileAttrName::Pseudo.description(), Some("The name shown to the other users.\n\nIt has to be unique."));
+    assert_eq!(ProfileAttrName::Avatar.description(), Some("Raw bytes of the avatar"));
+    assert_eq!(ProfileAttrName::Position.description(), None);
+
+    assert_eq!(ProfileAttrName::Pseudo.label(), "Pseudo");
+    assert_eq!(ProfileAttrName::Avatar.label(), "avatar");
+
+    assert_eq!(ProfileAttrName::Pseudo.placeholder(), Some("john_doe"));
+    assert_eq!(ProfileAttrName::Value.placeholder(), None);
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(ProfileAttrName::Pseudo.meta("widget"), Some("text"));
+    assert_eq!(ProfileAttrName::Pseudo.meta("autocomplete"), Some("username"));
+    assert_eq!(ProfileAttrName::Position.meta("widget"), Some("map"));
+    assert_eq!(ProfileAttrName::Position.meta("autocomplete"), None);
+    assert_eq!(ProfileAttrName::Value.meta("widget"), None);
+}
@@ -0,0 +1,65 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+pub struct Exact {
+    first_name: String,
+    last_name: String,
+    age: u8,
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_names(case_insensitive)]
+pub struct Insensitive {
+    first_name: String,
+    age: u8,
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_names(normalized)]
+pub struct Normalized {
+    first_name: String,
+    age: u8,
+}
+
+#[test]
+fn exact() {
+    assert_eq!("first_name".parse(), Ok(ExactAttrName::FirstName));
+    assert!("First_Name".parse::<ExactAttrName>().is_err());
+}
+
+#[test]
+fn case_insensitive() {
+    assert_eq!("FIRST_NAME".parse(), Ok(InsensitiveAttrName::FirstName));
+    assert_eq!("Age".parse(), Ok(InsensitiveAttrName::Age));
+    assert!("firstName".parse::<InsensitiveAttrName>().is_err());
+}
+
+#[test]
+fn normalized() {
+    for name in ["first_name", "firstName", "FirstName", "first-name", "FIRST_NAME"] {
+        assert_eq!(name.parse(), Ok(NormalizedAttrName::FirstName));
+    }
+    assert!("first name".parse::<NormalizedAttrName>().is_err());
+
+    let mut form = Normalized::empty_form::<()>();
+    form.set_data("firstName", NormalizedAttrData::FirstName("Anna".to_string())).unwrap();
+    assert!(form.get_data(NormalizedAttrName::FirstName).unwrap().is_some());
+}
+
+#[test]
+fn suggestions() {
+    let error = "frist_name".parse::<ExactAttrName>().unwrap_err();
+    assert_eq!(error, StrunemixFromError::NotAnEnumNameDidYouMean {
+        name: "frist_name".to_string(),
+        enum_name: "ExactAttrName".to_string(),
+        suggestions: vec!["first_name"],
+    });
+    assert_eq!(error.to_string(), "The string 'frist_name' is not a valid for the enum ExactAttrName, did you mean 'first_name'?");
+
+    let error = "Agee".parse::<InsensitiveAttrName>().unwrap_err();
+    assert_eq!(error.to_string(), "The string 'Agee' is not a valid for the enum InsensitiveAttrName, did you mean 'age'?");
+
+    let error = "height".parse::<ExactAttrName>().unwrap_err();
+    assert_eq!(error, StrunemixFromError::NotAnEnumName("height".to_string(), "ExactAttrName".to_string()));
+    assert_eq!(error.to_string(), "The string 'height' is not a valid for the enum ExactAttrName");
+}
@@ -0,0 +1,121 @@
+use std::sync::{Arc, Mutex};
+
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_parse]
+pub struct Person {
+    pseudo: String,
+    age: i32,
+}
+
+type Log = Arc<Mutex<Vec<(&'static str, Option<PersonAttrData>, Option<PersonAttrData>)>>>;
+
+fn observed() -> (StrunemixForm<PersonAttrName, PersonAttrData, 2, u8>, Log) {
+    let log = Log::default();
+    let mut form = Person {pseudo: "Anna".to_string(), age: 25}.to_form::<u8>();
+
+    let seen = log.clone();
+    form.on_any_change(move |name, change| {
+        if let Change::Data { old, new } = change {
+            seen.lock().unwrap().push((name.get_str(), old.cloned(), new.cloned()));
+        }
+    });
+
+    (form, log)
+}
+
+#[test]
+fn data_changes() -> Result<(), StrunemixError> {
+    let (mut form, log) = observed();
+
+    form.set_data_str("age", "26")?;
+    form.set_data("age", PersonAttrData::Age(27))?;
+    form.remove_data("pseudo")?;
+    assert!(form.set_data_str("age", "old").is_err());
+
+    assert_eq!(*log.lock().unwrap(), [
+        ("age", Some(PersonAttrData::Age(25)), Some(PersonAttrData::Age(26))),
+        ("age", Some(PersonAttrData::Age(26)), Some(PersonAttrData::Age(27))),
+        ("pseudo", Some(PersonAttrData::Pseudo("Anna".to_string())), None),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn reset_and_undo() -> Result<(), StrunemixError> {
+    let (mut form, log) = observed();
+    form.enable_history(10);
+
+    form.set_data_str("age", "26")?;
+    form.reset("age")?;
+    assert!(form.undo());
+
+    assert_eq!(*log.lock().unwrap(), [
+        ("age", Some(PersonAttrData::Age(25)), Some(PersonAttrData::Age(26))),
+        ("age", Some(PersonAttrData::Age(26)), Some(PersonAttrData::Age(25))),
+        ("age", Some(PersonAttrData::Age(25)), Some(PersonAttrData::Age(26))),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn field_callbacks() -> Result<(), StrunemixError> {
+    let (mut form, _) = observed();
+    let infos = Arc::new(Mutex::new(Vec::new()));
+    let ages = Arc::new(Mutex::new(0));
+
+    let seen = infos.clone();
+    form.on_change("pseudo", move |_, change| {
+        if let Change::Info { old, new } = change {
+            seen.lock().unwrap().push((*old, *new));
+        }
+    })?;
+
+    let count = ages.clone();
+    form.on_change(PersonAttrName::Age, move |_, _| *count.lock().unwrap() += 1)?;
+
+    assert!(form.on_change("name", |_, _| {}).is_err());
+
+    form.set_info("pseudo", 1)?;
+    form.set_info("pseudo", 2)?;
+    form.set_info("age", 3)?;
+    form.set_data_str("pseudo", "Lee")?;
+
+    assert_eq!(*infos.lock().unwrap(), [(0, 1), (1, 2)]);
+    assert_eq!(*ages.lock().unwrap(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn clone_and_clear() -> Result<(), StrunemixError> {
+    let (mut form, log) = observed();
+
+    let mut cloned = form.clone();
+    assert_eq!(cloned, form);
+    cloned.set_data_str("age", "26")?;
+    assert!(log.lock().unwrap().is_empty());
+
+    form.clear_observers();
+    form.set_data_str("age", "26")?;
+    assert!(log.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn observed_forms_are_send() {
+    assert_send::<StrunemixForm<PersonAttrName, PersonAttrData, 2, u8>>();
+
+    let (form, log) = observed();
+    let mut form = std::thread::spawn(move || form).join().unwrap();
+
+    form.set_data_str("age", "26").unwrap();
+    assert_eq!(log.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,62 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_parse]
+#[strunemix_reflect]
+pub struct Person {
+    pseudo: String,
+    #[strunemix(range(max = 150))]
+    age: u8,
+    #[strunemix(skip)]
+    secret: (),
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_parse]
+#[strunemix_reflect]
+pub struct Measure<V: std::str::FromStr + std::fmt::Debug + 'static> {
+    unit: String,
+    value: V,
+}
+
+fn records() -> Vec<Box<dyn StrunemixDyn>> {
+    vec![
+        Box::new(Person { pseudo: "Anna".to_string(), age: 25, secret: () }),
+        Box::new(Measure { unit: "kg".to_string(), value: 1.5f32 }),
+        Box::new(Measure { unit: "step".to_string(), value: 1200u32 }),
+    ]
+}
+
+#[test]
+fn list_fields() {
+    let records = records();
+
+    let names: Vec<_> = records.iter().map(|record| (record.type_name(), record.field_names())).collect();
+    assert_eq!(names, [("Person", &["pseudo", "age"][..]), ("Measure", &["unit", "value"]), ("Measure", &["unit", "value"])]);
+
+    assert_eq!(records[0].get_field::<String>("pseudo").map(String::as_str), Some("Anna"));
+    assert_eq!(records[1].get_field::<f32>("value"), Some(&1.5));
+    assert_eq!(records[2].get_field::<f32>("value"), None);
+    assert!(records[0].get_field_any("secret").is_none());
+
+    let debug: Vec<_> = records.iter().filter_map(|record| record.field_debug("value")).map(|value| format!("{value:?}")).collect();
+    assert_eq!(debug, ["1.5", "1200"]);
+}
+
+#[test]
+fn set_fields() {
+    let mut records = records();
+
+    records[0].set_field_str("age", "30").unwrap();
+    assert_eq!(records[0].get_field::<u8>("age"), Some(&30));
+
+    let error = records[0].set_field_str("age", "200").unwrap_err();
+    assert!(matches!(error, StrunemixError::Constraint(_)));
+
+    let error = records[1].set_field_str("value", "heavy").unwrap_err();
+    assert_eq!((error.field(), error.input()), (Some("value"), Some("heavy")));
+
+    let error = records[2].set_field_str("vlue", "3").unwrap_err();
+    assert!(matches!(&error, StrunemixError::ConversionError(error) if error.to_string().ends_with("did you mean 'value'?")));
+    assert_eq!(records[2].get_field::<u32>("value"), Some(&1200));
+}
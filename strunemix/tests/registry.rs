@@ -0,0 +1,94 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_parse]
+#[strunemix_register]
+pub struct Person {
+    pseudo: String,
+    #[strunemix(range(max = 150))]
+    age: u8,
+}
+
+#[derive(Debug, PartialEq, Default, Strunemix)]
+#[strunemix_parse]
+#[strunemix_default]
+#[strunemix_register]
+pub struct Pet {
+    name: String,
+    vaccinated: bool,
+    #[strunemix(skip)]
+    owner: Option<String>,
+}
+
+mod plugin {
+    use strunemix::*;
+
+    #[derive(Debug, PartialEq, Strunemix)]
+    #[strunemix_parse]
+    #[strunemix_register]
+    pub struct Person {
+        pub name: String,
+    }
+}
+
+#[test]
+fn list_types() {
+    let mut names: Vec<_> = StrunemixRegistry::type_names().collect();
+    names.sort();
+
+    assert_eq!(names, ["Person", "Person", "Pet"]);
+    assert!(StrunemixRegistry::contains("Pet"));
+    assert!(StrunemixRegistry::contains("registry::plugin::Person"));
+    assert!(!StrunemixRegistry::contains("Plant"));
+
+    let mut paths: Vec<_> = StrunemixRegistry::type_paths().collect();
+    paths.sort();
+
+    assert_eq!(paths, ["registry::Person", "registry::Pet", "registry::plugin::Person"]);
+}
+
+#[test]
+fn same_names() {
+    let error = StrunemixRegistry::build("Person", [("name", "Anna")]).err().unwrap();
+    assert!(matches!(error, StrunemixError::AmbiguousType { ref type_paths, .. } if type_paths.len() == 2));
+    assert!(error.to_string().starts_with("The type 'Person' is registered more than once, as registry::"));
+
+    let person = StrunemixRegistry::build("registry::plugin::Person", [("name", "Anna")]).unwrap();
+    assert_eq!(person.downcast_ref::<plugin::Person>(), Some(&plugin::Person { name: "Anna".to_string() }));
+
+    let person = StrunemixRegistry::build("registry::Person", [("pseudo", "Anna"), ("age", "25")]).unwrap();
+    assert_eq!(person.downcast_ref::<Person>(), Some(&Person { pseudo: "Anna".to_string(), age: 25 }));
+}
+
+#[test]
+fn build_by_name() {
+    let records = [
+        ("registry::Person", vec![("pseudo", "Anna"), ("age", "25")]),
+        ("Pet", vec![("vaccinated", "true"), ("name", "Rex")]),
+    ];
+
+    let records: Vec<Box<dyn StrunemixDyn>> = records.into_iter()
+        .map(|(type_name, pairs)| StrunemixRegistry::build(type_name, pairs).unwrap())
+        .collect();
+
+    assert_eq!(records[0].downcast_ref::<Person>(), Some(&Person { pseudo: "Anna".to_string(), age: 25 }));
+    assert_eq!(records[1].get_field::<String>("name").map(String::as_str), Some("Rex"));
+
+    let pet = records.into_iter().nth(1).unwrap().downcast::<Pet>().unwrap();
+    assert_eq!(*pet, Pet { name: "Rex".to_string(), vaccinated: true, owner: None });
+}
+
+#[test]
+fn build_errors() {
+    let error = StrunemixRegistry::build("Plant", [("name", "Fern")]).err().unwrap();
+    assert!(matches!(error, StrunemixError::UnknownType(ref name) if name == "Plant"));
+
+    let error = StrunemixRegistry::build("registry::Person", [("pseudo", "Anna"), ("age", "200")]).err().unwrap();
+    assert_eq!(error.field(), Some("age"));
+
+    let error = StrunemixRegistry::build("registry::Person", [("pseudo", "Anna")]).err().unwrap();
+    assert!(matches!(error, StrunemixError::IncompleteForm));
+
+    let error = StrunemixRegistry::build("Pet", [("nmae", "Rex")]).err().unwrap();
+    assert!(matches!(error, StrunemixError::ConversionError(StrunemixFromError::NotAnEnumName(..))));
+}
@@ -69,7 +69,7 @@ fn more_than_once() -> Result<(), StrunemixError> {
 
     let error = Person::from_attr_data_array(data).unwrap_err();
 
-    assert_eq!(error, StrunemixFromError::AppearedMoreThanOnce);
+    assert_eq!(error, StrunemixFromError::MissingOrDuplicated { missing: vec!["age"], duplicated: vec!["name"] });
 
     Ok(())
 }
@@ -127,13 +127,13 @@ fn form_empty() -> Result<(), StrunemixError> {
     assert_eq!(person.get_data(PersonAttrName::Age)?, None);
 
     let finished = person.is_complete();
-    assert_eq!(finished, false);
+    assert!(!finished);
 
     let failed = Person::from_form(person).unwrap_err();
 
     match failed {
         StrunemixError::IncompleteForm => (),
-        _ => assert!(false),
+        _ => panic!("expected an incomplete form error"),
     }
 
     Ok(())
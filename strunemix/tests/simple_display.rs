@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_display]
+pub struct Person {
+    name: String,
+    age: i32,
+    #[strunemix(skip)]
+    phone: Vec<String>,
+}
+
+impl<'a> StrunemixParsableData<'a, PersonAttrData> for PersonAttrName {
+    fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
+        match &self {
+            PersonAttrName::Name => Ok(PersonAttrData::Name(data.to_string())),
+            PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?))
+        }
+    }
+}
+
+#[test]
+fn to_data_string() {
+    assert_eq!(PersonAttrData::Name("John".to_string()).to_data_string(), "John");
+    assert_eq!(PersonAttrData::Age(-42).to_data_string(), "-42");
+}
+
+#[test]
+fn form() -> Result<(), StrunemixError> {
+    let person = Person {name: "John".to_string(), age: 42, phone: vec![]};
+
+    let mut form = person.to_form::<()>();
+
+    assert_eq!(form.get_data_str(PersonAttrName::Name)?, Some("John".to_string()));
+    assert_eq!(form.get_data_str("age")?, Some("42".to_string()));
+
+    form.remove_data("age")?;
+    assert_eq!(form.get_data_str("age")?, None);
+
+    Ok(())
+}
+
+#[test]
+fn round_trip() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    form.set_data_str("age", "42")?;
+    let age = form.get_data_str("age")?.unwrap();
+
+    form.set_data_str("age", &age)?;
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(42)));
+
+    Ok(())
+}
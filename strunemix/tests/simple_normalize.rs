@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use strunemix::*;
+
+fn digits_only(data: &str) -> String {
+    data.chars().filter(char::is_ascii_digit).collect()
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Person {
+    #[strunemix(trim, collapse_whitespace)]
+    name: String,
+    #[strunemix(trim, lowercase)]
+    email: Option<String>,
+    #[strunemix(transform = digits_only)]
+    phone: u64,
+    #[strunemix(trim)]
+    tags: Vec<String>,
+    age: u8,
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+pub struct Code {
+    #[strunemix(trim, uppercase)]
+    code: String,
+}
+
+impl StrunemixParsableData<'_, CodeAttrData> for CodeAttrName {
+    fn add_data(&self, data: Cow<str>) -> Result<CodeAttrData, StrunemixParseError> {
+        match self {
+            CodeAttrName::Code => Ok(CodeAttrData::Code(data.into_owned())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+pub struct Label<'a> {
+    #[strunemix(trim)]
+    label: &'a str,
+}
+
+impl<'a> StrunemixParsableData<'a, LabelAttrData<'a>> for LabelAttrName {
+    fn add_data(&self, data: Cow<'a, str>) -> Result<LabelAttrData<'a>, StrunemixParseError> {
+        match (self, data) {
+            (LabelAttrName::Label, Cow::Borrowed(data)) => Ok(LabelAttrData::Label(data)),
+            (LabelAttrName::Label, Cow::Owned(_)) => Err(StrunemixParseError::other("the label can't be borrowed")),
+        }
+    }
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(PersonAttrName::Name.normalize(" John \t Doe  "), "John Doe");
+    assert_eq!(PersonAttrName::Email.normalize(" John@Example.COM "), "john@example.com");
+    assert_eq!(PersonAttrName::Phone.normalize("+33 (0)6-12"), "330612");
+    assert_eq!(PersonAttrName::Age.normalize(" 42 "), " 42 ");
+}
+
+#[test]
+fn transforms() {
+    use std::borrow::Cow;
+
+    assert!(matches!(StrunemixTransform::trim(Cow::Borrowed(" a ")), Cow::Borrowed("a")));
+    assert!(matches!(StrunemixTransform::lowercase(Cow::Borrowed("a")), Cow::Borrowed("a")));
+    assert!(matches!(StrunemixTransform::collapse_whitespace(Cow::Borrowed(" a b ")), Cow::Borrowed(" a b ")));
+    assert_eq!(StrunemixTransform::collapse_whitespace(Cow::Borrowed("\ta \n b  ")), " a b ");
+    assert_eq!(StrunemixTransform::uppercase(Cow::Borrowed("Straße")), "STRASSE");
+}
+
+#[test]
+fn derived_parsing() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    form.set_data_str("name", "  Jane   Doe ")?;
+    form.set_data_str("email", "   ")?;
+    form.set_data_str("phone", "06 12 34")?;
+    form.set_data_str("tags", " a,b ")?;
+    form.set_data_str("age", "42")?;
+
+    assert!(form.set_data_str("age", " 42").is_err());
+
+    let person = Person::from_form(form)?;
+
+    assert_eq!(person, Person {
+        name: "Jane Doe".to_string(),
+        email: None,
+        phone: 61234,
+        tags: vec!["a".to_string(), "b".to_string()],
+        age: 42,
+    });
+
+    Ok(())
+}
+
+#[test]
+fn hand_written_parsing() -> Result<(), StrunemixError> {
+    let mut form = Code::empty_form::<()>();
+    form.set_data_str("code", " ab12 ")?;
+
+    assert_eq!(form.get_data("code")?, Some(&CodeAttrData::Code("AB12".to_string())));
+    assert_eq!(CodeAttrName::Code.add_data(" ab12 ".into())?, CodeAttrData::Code(" ab12 ".to_string()));
+
+    let mut form = Label::empty_form::<()>();
+    form.set_data_str("label", " new ")?;
+
+    assert_eq!(form.get_data("label")?, Some(&LabelAttrData::Label("new")));
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use strunemix::*;
 
 
@@ -9,7 +10,7 @@ pub struct Person {
 }
 
 impl<'a> StrunemixParsableData<'a, PersonAttrData> for PersonAttrName {
-    fn add_data(&self, data: &str) -> Result<PersonAttrData, StrunemixParseError> {
+    fn add_data(&self, data: Cow<str>) -> Result<PersonAttrData, StrunemixParseError> {
         match &self {
             PersonAttrName::Name => Ok(PersonAttrData::Name(data.to_string())),
             PersonAttrName::Age => Ok(PersonAttrData::Age(data.parse()?))
@@ -21,7 +22,7 @@ impl<'a> StrunemixParsableData<'a, PersonAttrData> for PersonAttrName {
 fn from_name(){
     let name = PersonAttrName::Name;
 
-    let name_data = name.add_data("John").unwrap();
+    let name_data = name.add_data("John".into()).unwrap();
     assert_eq!(name_data, PersonAttrData::Name("John".to_string()));
 }
 
@@ -29,7 +30,7 @@ fn from_name(){
 fn from_string(){
     let name = "name";
     let data = "John";
-    let name_data = PersonAttrName::from_str(name).unwrap().add_data(data).unwrap();
+    let name_data = PersonAttrName::from_str(name).unwrap().add_data(data.into()).unwrap();
 
     assert_eq!(name_data, PersonAttrData::Name("John".to_string()));
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Record {
+    name: String,
+    age: u8,
+    score: Option<f64>,
+    nick: Option<String>,
+    #[strunemix(delimiter = ";")]
+    tags: Vec<String>,
+    ids: Vec<u32>,
+    pair: (bool, Option<i32>),
+    #[strunemix(separator = ":")]
+    range: (u8, u8),
+    #[strunemix(delimiter = " ", separator = "=")]
+    entries: Vec<(String, Option<u8>)>,
+    unit: (),
+}
+
+#[test]
+fn leaves() -> Result<(), StrunemixParseError> {
+    assert_eq!(RecordAttrName::Name.add_data("John".into())?, RecordAttrData::Name("John".to_string()));
+    assert_eq!(RecordAttrName::Age.add_data("42".into())?, RecordAttrData::Age(42));
+    assert_eq!(RecordAttrName::Unit.add_data("anything".into())?, RecordAttrData::Unit(()));
+
+    assert!(matches!(RecordAttrName::Age.add_data("300".into()), Err(StrunemixParseError::ParseIntError(_))));
+
+    Ok(())
+}
+
+#[test]
+fn option() -> Result<(), StrunemixParseError> {
+    assert_eq!(RecordAttrName::Score.add_data("1.5".into())?, RecordAttrData::Score(Some(1.5)));
+    assert_eq!(RecordAttrName::Score.add_data("".into())?, RecordAttrData::Score(None));
+    assert_eq!(RecordAttrName::Score.add_data("null".into())?, RecordAttrData::Score(None));
+    assert_eq!(RecordAttrName::Nick.add_data("".into())?, RecordAttrData::Nick(None));
+    assert_eq!(RecordAttrName::Nick.add_data("Jo".into())?, RecordAttrData::Nick(Some("Jo".to_string())));
+
+    assert!(matches!(RecordAttrName::Score.add_data("high".into()), Err(StrunemixParseError::ParseFloatError(_))));
+
+    Ok(())
+}
+
+#[test]
+fn vec() -> Result<(), StrunemixParseError> {
+    assert_eq!(RecordAttrName::Ids.add_data("15,16,17".into())?, RecordAttrData::Ids(vec![15, 16, 17]));
+    assert_eq!(RecordAttrName::Ids.add_data("".into())?, RecordAttrData::Ids(vec![]));
+    assert_eq!(RecordAttrName::Tags.add_data("a,b;c".into())?, RecordAttrData::Tags(vec!["a,b".to_string(), "c".to_string()]));
+
+    assert!(RecordAttrName::Ids.add_data("15,,17".into()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn tuple() -> Result<(), StrunemixParseError> {
+    assert_eq!(RecordAttrName::Pair.add_data("true,13".into())?, RecordAttrData::Pair((true, Some(13))));
+    assert_eq!(RecordAttrName::Pair.add_data("false".into())?, RecordAttrData::Pair((false, None)));
+    assert_eq!(RecordAttrName::Pair.add_data("false,".into())?, RecordAttrData::Pair((false, None)));
+    assert_eq!(RecordAttrName::Range.add_data("1:9".into())?, RecordAttrData::Range((1, 9)));
+
+    assert!(RecordAttrName::Range.add_data("1".into()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn nested() -> Result<(), StrunemixParseError> {
+    let expected = vec![("a".to_string(), Some(1)), ("b".to_string(), None)];
+    assert_eq!(RecordAttrName::Entries.add_data("a=1 b".into())?, RecordAttrData::Entries(expected));
+
+    Ok(())
+}
+
+#[test]
+fn form() -> Result<(), StrunemixError> {
+    let mut form = Record::empty_form::<()>();
+
+    form.set_data_str("name", "John")?;
+    form.set_data_str("age", "42")?;
+    form.set_data_str("score", "")?;
+    form.set_data_str("nick", "null")?;
+    form.set_data_str("tags", "")?;
+    form.set_data_str("ids", "1,2")?;
+    form.set_data_str("pair", "true,-1")?;
+    form.set_data_str("range", "0:5")?;
+    form.set_data_str("entries", "x=3")?;
+    form.set_data_str("unit", "")?;
+
+    let record = Record::from_form(form)?;
+
+    assert_eq!(record, Record {
+        name: "John".to_string(),
+        age: 42,
+        score: None,
+        nick: None,
+        tags: vec![],
+        ids: vec![1, 2],
+        pair: (true, Some(-1)),
+        range: (0, 5),
+        entries: vec![("x".to_string(), Some(3))],
+        unit: (),
+    });
+
+    Ok(())
+}
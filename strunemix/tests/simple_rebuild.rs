@@ -69,4 +69,45 @@ fn form() -> Result<(), StrunemixError> {
 
     Ok(())
 
-}
\ No newline at end of file
+}
+
+#[derive(Debug, PartialEq, Eq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_derive_name(Debug, PartialEq)]
+pub struct Person3 {
+    name: String,
+    age: i32,
+    city: String,
+}
+
+#[test]
+fn rebuild_any_order() {
+    let data = [Person3AttrData::City("Paris".to_string()), Person3AttrData::Name("John".to_string()), Person3AttrData::Age(42)];
+
+    let person = Person3::from_attr_data_array(data).unwrap();
+
+    assert_eq!(person, Person3 {name: "John".to_string(), age: 42, city: "Paris".to_string()});
+}
+
+#[test]
+fn rebuild_missing_and_duplicated() {
+    let data = [Person3AttrData::Age(1), Person3AttrData::Name("John".to_string()), Person3AttrData::Age(2)];
+
+    let error = Person3::from_attr_data_array(data).unwrap_err();
+
+    assert_eq!(error, StrunemixFromError::MissingOrDuplicated { missing: vec!["city"], duplicated: vec!["age"] });
+    assert_eq!(error.to_string(), "Invalid input data, missing: [city], duplicated: [age]");
+}
+
+#[test]
+fn rebuild_from_slots() {
+    let slots = [Some(Person3AttrData::Name("John".to_string())), None, Some(Person3AttrData::City("Paris".to_string()))];
+    let error = Person3::from_data_slots(slots).unwrap_err();
+    assert_eq!(error, StrunemixFromError::MissingOrDuplicated { missing: vec!["age"], duplicated: vec![] });
+
+    let slots = [Some(Person3AttrData::Age(42)), Some(Person3AttrData::Name("John".to_string())), Some(Person3AttrData::City("Paris".to_string()))];
+    assert_eq!(Person3::from_data_slots(slots).unwrap_err(), StrunemixFromError::WrongOrder);
+
+    let slots = [Some(Person3AttrData::Name("John".to_string())), Some(Person3AttrData::Age(42)), Some(Person3AttrData::City("Paris".to_string()))];
+    assert_eq!(Person3::from_data_slots(slots).unwrap(), Person3 {name: "John".to_string(), age: 42, city: "Paris".to_string()});
+}
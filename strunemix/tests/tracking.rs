@@ -0,0 +1,142 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Person {
+    name: String,
+    age: i32,
+    email: Option<String>,
+}
+
+fn person() -> Person {
+    Person {name: "John".to_string(), age: 42, email: None}
+}
+
+#[test]
+fn untouched() {
+    let form = person().to_form::<()>();
+
+    assert!(form.touched_fields().is_empty());
+    assert!(form.dirty_fields().is_empty());
+    assert!(!form.is_dirty());
+}
+
+#[test]
+fn touched_and_dirty() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    form.set_data_str("name", "John")?;
+    form.set_data_str("age", "43")?;
+    form.set_info("email", ())?;
+
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Name, &PersonAttrName::Age]);
+    assert_eq!(form.dirty_fields(), [&PersonAttrName::Age]);
+    assert!(form.is_dirty());
+
+    form.set_data_str("age", "42")?;
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Name, &PersonAttrName::Age]);
+    assert!(!form.is_dirty());
+
+    Ok(())
+}
+
+#[test]
+fn empty_form() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    assert!(form.get_data_mut("age")?.is_none());
+    form.set_data_str("email", "")?;
+    form.remove_data("name")?;
+
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Name, &PersonAttrName::Email]);
+    assert_eq!(form.dirty_fields(), [&PersonAttrName::Email]);
+
+    Ok(())
+}
+
+#[test]
+fn reset() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    form.set_data_str("name", "Jane")?;
+    form.set_data_str("name", "Jill")?;
+    form.remove_data("age")?;
+
+    form.reset("name")?;
+    form.reset(PersonAttrName::Age)?;
+    form.reset("email")?;
+
+    assert!(form.touched_fields().is_empty());
+    assert_eq!(Person::from_form(form)?, person());
+
+    Ok(())
+}
+
+#[test]
+fn changed_in_place() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    if let Some(PersonAttrData::Age(age)) = form.get_data_mut("age")? {
+        *age += 1;
+    }
+
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Age]);
+    assert_eq!(form.dirty_fields(), [&PersonAttrName::Age]);
+
+    assert!(matches!(form.reset("age"), Err(StrunemixError::InitialDataLost)));
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(43)));
+
+    form.set_data_str("name", "Jane")?;
+    if let Some(PersonAttrData::Name(name)) = form.get_data_mut("name")? {
+        name.push('!');
+    }
+    form.reset("name")?;
+    assert_eq!(form.get_data("name")?, Some(&PersonAttrData::Name("John".to_string())));
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+pub struct Account {
+    login: String,
+    credits: u32,
+}
+
+#[test]
+fn borrowed_with_snapshot() -> Result<(), StrunemixError> {
+    let mut form = Account {login: "john".to_string(), credits: 10}.to_form::<()>();
+
+    assert!(form.get_data_mut("login")?.is_some());
+    for (_, data, _) in form.iter_mut() {
+        assert!(data.is_some());
+    }
+    assert_eq!(form.touched_fields(), [&AccountAttrName::Login]);
+
+    form.reset("login")?;
+    for (name, mut data, _) in form.iter_mut() {
+        if let Some(AccountAttrData::Credits(credits)) = data.as_deref() {
+            assert_eq!(*credits, 10);
+        }
+        if name == AccountAttrName::Login {
+            assert!(data.as_deref_mut().is_some());
+        }
+    }
+    assert_eq!(form.touched_fields(), [&AccountAttrName::Login]);
+
+    assert!(!form.is_dirty());
+    form.reset("login")?;
+    form.reset("credits")?;
+
+    if let Some(AccountAttrData::Credits(credits)) = form.get_data_mut("credits")? {
+        *credits += 5;
+    }
+    assert_eq!(form.dirty_fields(), [&AccountAttrName::Credits]);
+
+    form.reset("credits")?;
+    assert_eq!(form.get_data("credits")?, Some(&AccountAttrData::Credits(10)));
+    assert!(!form.is_dirty());
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_parse]
+pub struct Person {
+    pseudo: String,
+    #[strunemix(range(min = 0))]
+    age: i32,
+    height: f32,
+}
+
+fn person() -> Person {
+    Person {pseudo: "Anna".to_string(), age: 25, height: 1.7}
+}
+
+#[test]
+fn rollback() {
+    let mut form = person().to_form::<()>();
+
+    let res = form.transaction(|tx| {
+        tx.set_data_str("pseudo", "Lee")?;
+        tx.remove_data("height")?;
+        tx.set_data_str("age", "26")?;
+        tx.set_data_str("age", "-1")
+    });
+
+    assert!(matches!(res, Err(StrunemixError::Constraint(_))));
+    assert_eq!(form, person().to_form::<()>());
+    assert!(form.touched_fields().is_empty());
+}
+
+#[test]
+fn commit() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    let age = form.transaction(|tx| {
+        tx.set_data_str("pseudo", "Lee")?;
+        tx.set_data_str("age", "26")?;
+        Ok::<_, StrunemixError>(26)
+    })?;
+
+    assert_eq!(age, 26);
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("Lee".to_string())));
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Pseudo, &PersonAttrName::Age]);
+
+    Ok(())
+}
+
+#[test]
+fn nested() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+
+    let res = form.transaction(|tx| {
+        tx.set_data_str("pseudo", "Lee")?;
+
+        let inner = tx.transaction(|tx| tx.set_data_str("age", "old"));
+        assert!(inner.is_err());
+        assert_eq!(tx.get_data("age")?, Some(&PersonAttrData::Age(25)));
+
+        tx.transaction(|tx| tx.set_data_str("age", "30"))?;
+        tx.set_data_str("height", "tall")
+    });
+
+    assert!(res.is_err());
+    assert_eq!(form, person().to_form::<()>());
+
+    Ok(())
+}
+
+#[test]
+fn with_history() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+
+    form.set_data_str("height", "1.8")?;
+    assert!(form.transaction(|tx| tx.set_data_str("age", "-3")).is_err());
+
+    form.transaction(|tx| {
+        tx.set_data_str("pseudo", "Lee")?;
+        tx.set_data_str("age", "26")
+    })?;
+
+    assert!(form.undo());
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("Anna".to_string())));
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    assert_eq!(form.get_data("height")?, Some(&PersonAttrData::Height(1.8)));
+
+    assert!(form.undo());
+    assert!(!form.can_undo());
+
+    Ok(())
+}
+
+#[test]
+fn history_operations() -> Result<(), StrunemixError> {
+    let mut form = person().to_form::<()>();
+    form.enable_history(10);
+    form.set_data_str("height", "1.8")?;
+
+    let res = form.transaction(|tx| {
+        tx.set_data_str("pseudo", "Lee")?;
+        assert!(!tx.undo());
+        tx.disable_history();
+        tx.set_data_str("age", "26")?;
+        tx.enable_history(10);
+        tx.set_data_str("age", "-1")
+    });
+
+    assert!(res.is_err());
+    assert_eq!(form.get_data("pseudo")?, Some(&PersonAttrData::Pseudo("Anna".to_string())));
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    assert_eq!(form.get_data("height")?, Some(&PersonAttrData::Height(1.8)));
+    assert!(!form.can_undo());
+
+    Ok(())
+}
+
+#[test]
+fn set_many_str() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<()>();
+
+    let errors = form.set_many_str([("pseudo", "Lee"), ("age", "old"), ("weight", "80"), ("height", "1.8"), ("age", "-4")]).unwrap_err();
+
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(errors[0], StrunemixError::FieldParseError(StrunemixFieldParseError { field: "age", source: StrunemixParseError::ParseIntError(_), .. })));
+    assert!(matches!(errors[1], StrunemixError::ConversionError(_)));
+    assert!(matches!(errors[2], StrunemixError::Constraint(_)));
+    assert_eq!(form, Person::empty_form::<()>());
+
+    form.set_many_str([("pseudo", "Lee"), ("age", "26"), ("height", "1.8")]).map_err(|mut errors| errors.remove(0))?;
+    assert_eq!(Person::from_form(form)?, Person {pseudo: "Lee".to_string(), age: 26, height: 1.8});
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use strunemix::*;
+
+#[derive(Debug, Clone, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+pub struct Person {
+    pseudo: String,
+    #[strunemix(range(min = 0))]
+    age: i32,
+    height: f32,
+}
+
+#[test]
+fn try_from_form_keeps_the_form() -> Result<(), StrunemixError> {
+    let person = Person {pseudo: "Anna".to_string(), age: 25, height: 1.7};
+    let mut form = person.clone().to_form::<String>();
+    form.set_info("age", "years".to_string())?;
+
+    assert_eq!(Person::try_from_form(&form)?, person);
+    assert_eq!(form.get_info("age")?, "years");
+
+    assert!(form.set_data("age", PersonAttrData::Age(-1)).is_err());
+    if let Some(PersonAttrData::Age(age)) = form.get_data_mut("age")? {
+        *age = -1;
+    }
+    assert!(matches!(Person::try_from_form(&form), Err(StrunemixError::Constraint(_))));
+
+    form.remove_data("pseudo")?;
+    assert!(matches!(Person::try_from_form(&form), Err(StrunemixError::IncompleteForm)));
+
+    Ok(())
+}
+
+#[test]
+fn from_form_or_return_missing() -> Result<(), StrunemixError> {
+    let mut form = Person::empty_form::<String>();
+    form.set_data("age", PersonAttrData::Age(25))?;
+    form.set_info("pseudo", "required".to_string())?;
+
+    let error = Person::from_form_or_return(form).unwrap_err();
+    assert_eq!(error.missing, [PersonAttrName::Pseudo, PersonAttrName::Height]);
+    assert!(matches!(error.error, StrunemixError::IncompleteForm));
+    assert_eq!(error.to_string(), "Incomplete form");
+
+    let form = error.form;
+    assert_eq!(form.get_data("age")?, Some(&PersonAttrData::Age(25)));
+    assert_eq!(form.get_info("pseudo")?, "required");
+    assert_eq!(form.touched_fields(), [&PersonAttrName::Age]);
+
+    Ok(())
+}
+
+#[test]
+fn from_form_or_return_constraint() -> Result<(), StrunemixError> {
+    let mut form = Person {pseudo: "Anna".to_string(), age: 25, height: 1.7}.to_form::<()>();
+    if let Some(age) = form.get_data_mut("age")? {
+        *age = PersonAttrData::Age(-5);
+    }
+
+    let error = Person::from_form_or_return(form).unwrap_err();
+    assert!(error.missing.is_empty());
+    assert!(matches!(error.error, StrunemixError::Constraint(StrunemixConstraintError { field: "age", .. })));
+
+    let mut form = error.form;
+    form.set_data("age", PersonAttrData::Age(5))?;
+
+    let person = Person::from_form_or_return(form).map_err(|e| e.error)?;
+    assert_eq!(person, Person {pseudo: "Anna".to_string(), age: 5, height: 1.7});
+
+    Ok(())
+}
+
+/// A struct whose data can't be cloned.
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+pub struct Ticket {
+    title: String,
+    seats: u8,
+}
+
+#[test]
+fn from_form_or_return_moves_the_data() -> Result<(), StrunemixError> {
+    let mut form = Ticket::empty_form::<()>();
+    form.set_data("seats", TicketAttrData::Seats(2))?;
+
+    let error = Ticket::from_form_or_return(form).unwrap_err();
+    assert_eq!(error.missing, [TicketAttrName::Title]);
+
+    let mut form = error.form;
+    assert_eq!(form.get_data("seats")?, Some(&TicketAttrData::Seats(2)));
+    form.set_data("title", TicketAttrData::Title("Opera".to_string()))?;
+
+    assert_eq!(Ticket::from_form_or_return(form).map_err(|e| e.error)?, Ticket {title: "Opera".to_string(), seats: 2});
+
+    Ok(())
+}
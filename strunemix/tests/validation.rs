@@ -0,0 +1,109 @@
+use strunemix::*;
+
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq)]
+#[strunemix_parse]
+pub struct Signup {
+    pseudo: String,
+    password: String,
+    confirm: String,
+    age: i32,
+}
+
+fn validator() -> FormValidator<SignupAttrName, SignupAttrData, 4, Vec<String>> {
+    FormValidator::new()
+        .required(SignupAttrName::Pseudo)
+        .required(SignupAttrName::Age)
+        .field(SignupAttrName::Password, |password| match password {
+            SignupAttrData::Password(password) if password.len() < 8 => Err("is too short".to_string()),
+            _ => Ok(()),
+        })
+        .field(SignupAttrName::Password, |password| match password {
+            SignupAttrData::Password(password) if !password.chars().any(|c| c.is_ascii_digit()) => Err("needs a digit".to_string()),
+            _ => Ok(()),
+        })
+        .rule(|form| match (form.get_data(SignupAttrName::Password), form.get_data(SignupAttrName::Confirm)) {
+            (Ok(Some(SignupAttrData::Password(password))), Ok(Some(SignupAttrData::Confirm(confirm)))) if password != confirm => {
+                Err(ValidationError::field(&SignupAttrName::Confirm, "does not match the password"))
+            },
+            _ => Ok(()),
+        })
+}
+
+#[test]
+fn every_failure_reported() -> Result<(), StrunemixError> {
+    let mut form = Signup::empty_form::<Vec<String>>();
+    form.set_data_str("password", "abc")?;
+    form.set_data_str("confirm", "abd")?;
+
+    let report = form.validate(&validator()).unwrap_err();
+
+    assert_eq!(report.len(), 5);
+    assert_eq!(report.for_field(SignupAttrName::Pseudo).count(), 1);
+    assert_eq!(report.for_field(SignupAttrName::Age).count(), 1);
+    assert_eq!(report.for_field(SignupAttrName::Password).map(|error| error.message()).collect::<Vec<_>>(), ["is too short", "needs a digit"]);
+    assert_eq!(report.for_field(SignupAttrName::Confirm).count(), 1);
+    assert_eq!(report.for_form().count(), 0);
+    assert!(matches!(report.errors()[0].name(), Some(SignupAttrName::Pseudo)));
+
+    Ok(())
+}
+
+#[test]
+fn valid() -> Result<(), StrunemixError> {
+    let form = Signup {pseudo: "John".to_string(), password: "hunter22".to_string(), confirm: "hunter22".to_string(), age: 42}.to_form();
+
+    assert_eq!(form.validate(&validator()), Ok(()));
+
+    Ok(())
+}
+
+#[test]
+fn store_in_info() -> Result<(), StrunemixError> {
+    let mut form = Signup::empty_form::<Vec<String>>();
+    form.set_data_str("pseudo", "John")?;
+    form.set_data_str("password", "hunter")?;
+    form.set_info("pseudo", vec!["old error".to_string()])?;
+
+    assert!(form.validate_and_store(&validator()).is_err());
+
+    assert!(form.get_info("pseudo")?.is_empty());
+    assert_eq!(form.get_info("age")?, &["is required"]);
+    assert_eq!(form.get_info("password")?, &["is too short", "needs a digit"]);
+
+    Ok(())
+}
+
+#[test]
+fn function_validator() -> Result<(), StrunemixError> {
+    let no_admin = |form: &StrunemixForm<SignupAttrName, SignupAttrData, 4>, report: &mut ValidationReport| {
+        if let Ok(Some(SignupAttrData::Pseudo(pseudo))) = form.get_data("pseudo") {
+            if pseudo == "admin" {
+                report.push(ValidationError::form("admin can't sign up"));
+            }
+        }
+    };
+
+    let form = Signup {pseudo: "admin".to_string(), password: String::new(), confirm: String::new(), age: 0}.to_form::<()>();
+
+    let report = form.validate(&no_admin).unwrap_err();
+    assert_eq!(report.for_form().count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn from_form_validated() -> Result<(), StrunemixError> {
+    let form = Signup {pseudo: "John".to_string(), password: "hunter22".to_string(), confirm: "hunter".to_string(), age: 42}.to_form();
+
+    match Signup::from_form_validated(form, &validator()) {
+        Err(StrunemixError::Invalid(report)) => assert_eq!(report.to_string(), "confirm: does not match the password"),
+        _ => panic!("expected an invalid form error"),
+    }
+
+    let form = Signup {pseudo: "John".to_string(), password: "hunter22".to_string(), confirm: "hunter22".to_string(), age: 42}.to_form();
+    let signup = Signup::from_form_validated(form, &validator())?;
+    assert_eq!(signup.pseudo, "John");
+
+    Ok(())
+}
@@ -2,31 +2,64 @@
 use std::iter::FromIterator;
 use proc_macro::TokenStream;
 use syn::{
-    DeriveInput, Ident, Type, Attribute, Fields, Meta, Path, PathArguments, PathSegment, punctuated::Punctuated
+    DeriveInput, Ident, Type, Attribute, Fields, Meta, Path, PathArguments, PathSegment, punctuated::Punctuated,
+    GenericArgument, GenericParam, Generics, LitStr, WhereClause, parse_quote
 };
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
-use heck::ToUpperCamelCase;
+use heck::{ToUpperCamelCase, ToShoutySnakeCase};
 
-#[proc_macro_derive(Strunemix, attributes(strunemix, strunemix_derive_data, strunemix_derive_name, strunemix_derive, strunemix_default))]
+#[proc_macro_derive(Strunemix, attributes(strunemix, strunemix_derive_data, strunemix_derive_name, strunemix_derive, strunemix_default, strunemix_display, strunemix_parse, strunemix_names, strunemix_dyn, strunemix_reflect, strunemix_register, strunemix_info))]
 pub fn field_type(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input).unwrap();
     let (vis, ty, generics) = (&ast.vis, &ast.ident, &ast.generics);
     let enum_data = Ident::new(&(ty.to_string() + "AttrData"), Span::call_site());
     let enum_name = Ident::new(&(ty.to_string() + "AttrName"), Span::call_site());
+    let enum_info = Ident::new(&(ty.to_string() + "AttrInfo"), Span::call_site());
     let derive_type = get_enum_derive(&ast.attrs, &["strunemix_derive_data", "strunemix_derive"], quote! {});
+    let derive_type_clone = enum_derives(&ast.attrs, &["strunemix_derive_data", "strunemix_derive"], "Clone");
     let derive_name = get_enum_derive(&ast.attrs, &["strunemix_derive_name", "strunemix_derive"], 
     quote! {#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)] }
     );
-    let fields = filter_fields(match ast.data {
+    let fields = match filter_fields(match ast.data {
         syn::Data::Struct(ref s) => &s.fields,
         _ => panic!("Strunemix can only derive structures")
-    });
+    }) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let have_default = ast.attrs.iter()
         .find(|attr| attr.path().is_ident("strunemix_default"))
         .is_some();
 
+    let have_display = ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_display"));
+
+    let have_parse = ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_parse"));
+
+    let have_dyn = ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_dyn"));
+
+    let have_info = ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_info"));
+
+    let have_register = ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_register"));
+
+    let have_reflect = have_register || ast.attrs.iter()
+        .any(|attr| attr.path().is_ident("strunemix_reflect"));
+
+    let name_matching = match get_name_matching(&ast.attrs) {
+        Ok(name_matching) => name_matching,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    if let Err(err) = check_name_collisions(&fields, &name_matching) {
+        return err.to_compile_error().into();
+    }
+
     let haveskippedfields = match ast.data {
         syn::Data::Struct(ref s) => &s.fields,
         _ => panic!("Strunemix can only derive structures")
@@ -37,41 +70,45 @@ pub fn field_type(input: TokenStream) -> TokenStream {
     }
 
     let field_name_constructs = fields.iter()
-        .map(|(_, _, variant_ident)| quote! {
+        .map(|(_, _, variant_ident, _)| quote! {
             #enum_name::#variant_ident
         });
 
     let field_type_to_variant = fields.iter()
-        .map(|(_, _, variant_ident)| quote! {
+        .map(|(_, _, variant_ident, _)| quote! {
             #enum_data::#variant_ident(_) => #enum_name::#variant_ident,
         });
 
     let from_field_name_constructs = field_name_constructs.clone();
 
     let field_name_variants = fields.iter()
-        .map(|(_, _, variant_ident)| quote! {
-            #variant_ident
+        .map(|(_, _, variant_ident, options)| {
+            let doc = options.description.iter();
+            quote! {
+                #(#[doc = #doc])*
+                #variant_ident
+            }
         });
 
     let field_type_variants = fields.iter()
-        .map(|(_, field_ty, variant_ident)| quote! {
+        .map(|(_, field_ty, variant_ident, _)| quote! {
             #variant_ident(#field_ty)
         });
 
     let field_type_constructs = fields.iter()
-        .map(|(field_ident, _, variant_ident)| quote! {
+        .map(|(field_ident, _, variant_ident, _)| quote! {
             #enum_data::#variant_ident(#field_ident)
         });
 
     let from_field_type_constructs = field_type_constructs.clone();
 
     let fields_idents = fields.iter()
-        .map(|(field_ident, _, _)| quote! {
+        .map(|(field_ident, _, _, _)| quote! {
             #field_ident
         });
     
     let field_name_by_strs = fields.iter()
-        .map(|(field_ident, _, variant_ident)| {
+        .map(|(field_ident, _, variant_ident, _)| {
             let field_name = field_ident.to_string();
             quote! {
                 #field_name => Ok(#enum_name::#variant_ident)
@@ -79,13 +116,29 @@ pub fn field_type(input: TokenStream) -> TokenStream {
         });
 
     let field_name_to_strs = fields.iter()
-        .map(|(field_ident, _, variant_ident)| {
+        .map(|(field_ident, _, variant_ident, _)| {
             let field_name = field_ident.to_string();
             quote! {
                 #enum_name::#variant_ident => #field_name
             }
         });
 
+    let field_name_by_indexes = fields.iter()
+        .enumerate()
+        .map(|(index, (_, _, variant_ident, _))| {
+            quote! {
+                #index => Some(#enum_name::#variant_ident)
+            }
+        });
+
+    let field_name_to_indexes = fields.iter()
+        .enumerate()
+        .map(|(index, (_, _, variant_ident, _))| {
+            quote! {
+                #enum_name::#variant_ident => #index
+            }
+        });
+
     let fields_idents_cpy = fields_idents.clone();
 
     let destructuring = quote! { #ty { #(#fields_idents_cpy,)* .. } };
@@ -95,10 +148,10 @@ pub fn field_type(input: TokenStream) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let parts = fields.iter()
-    .map(|(field_ident, _, variant_ident)| {
+    .map(|(field_ident, _, variant_ident, _)| {
         quote! {
             #field_ident: match #field_ident {
-                #enum_data::#variant_ident(value) => value,
+                Some(#enum_data::#variant_ident(value)) => value,
                 _ => return Err(StrunemixFromError::WrongOrder),
             }
         }
@@ -182,16 +235,45 @@ pub fn field_type(input: TokenStream) -> TokenStream {
         }
     };
 
-    let checks_more_than_once = quote! {
-        let mut namesall = source.iter().map(|data| data.name()).collect::<Vec<_>>();
-        namesall.dedup();
+    let field_names = fields.iter()
+        .map(|(field_ident, _, _, _)| field_ident.to_string());
+
+    let field_names_cpy = field_names.clone();
+    let slots_idents = fields_idents.clone();
+    let slots_parts = parts.clone();
 
-        if namesall.len() != #fields_count {
-            return Err(StrunemixFromError::AppearedMoreThanOnce);
+    let checks_more_than_once = quote! {
+        let mut counts = [0usize; #fields_count];
+        let mut slots: [Option<_>; #fields_count] = ::core::array::from_fn(|_| None);
+        for data in source {
+            let index = #enum_name::from(&data).index();
+            counts[index] += 1;
+            slots[index] = Some(data);
         }
 
-        let mut source = source;
-        source.sort_by(|a, b| a.name().cmp(&b.name()));
+        StrunemixFromError::check_counts(&[#(#field_names),*], &counts)?;
+        let source = slots;
+    };
+
+    let rest = match haveskippedfields {
+        true => quote! { ..Default::default() },
+        false => quote! {},
+    };
+
+    let from_data_slots = match haveskippedfields && !have_default {
+        true => quote! {},
+        false => quote! {
+            fn from_data_slots(slots: [Option<#enum_data #ty_generics>; #fields_count]) -> Result<Self, StrunemixFromError> {
+                StrunemixFromError::check_counts(&[#(#field_names_cpy),*], &slots.each_ref().map(|slot| slot.is_some() as usize))?;
+
+                let [#(#slots_idents),*] = slots;
+
+                Ok(#ty {
+                    #(#slots_parts,)*
+                    #rest
+                })
+            }
+        },
     };
 
     let tryfromarray = match (haveskippedfields, generics.params.is_empty(), !have_default) {
@@ -269,6 +351,473 @@ pub fn field_type(input: TokenStream) -> TokenStream {
 
     };
 
+    let displayable = if have_display {
+        let field_to_strings = fields.iter()
+            .map(|(_, _, variant_ident, _)| quote! {
+                #enum_data::#variant_ident(value) => __private::ToString::to_string(value)
+            });
+
+        let fields_to_strings = fields.iter()
+            .map(|(field_ident, _, _, _)| quote! {
+                __private::ToString::to_string(&self.#field_ident)
+            });
+
+        let display_bounds = fields.iter()
+            .map(|(_, field_ty, _, _)| quote! { #field_ty: ::core::fmt::Display });
+
+        let where_clause = extend_where(where_clause, generics, display_bounds);
+
+        quote! {
+            impl #impl_generics StrunemixDisplayableData<#enum_name> for #enum_data #ty_generics
+                #where_clause
+            {
+                fn to_data_string(&self) -> __private::String {
+                    match self {
+                        #(#field_to_strings),*
+                    }
+                }
+            }
+
+            impl #impl_generics StrunemixDisplayableFields<#fields_count> for #ty #ty_generics
+                #where_clause
+            {
+                fn to_data_strings(&self) -> [__private::String; #fields_count] {
+                    [#(#fields_to_strings),*]
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let dynamic = if have_dyn {
+        let field_kinds = fields.iter()
+            .map(|(_, field_ty, variant_ident, _)| quote! {
+                #enum_name::#variant_ident => <#field_ty as StrunemixDynValue>::dyn_kind()
+            });
+
+        let field_from_dyns = fields.iter()
+            .map(|(_, field_ty, variant_ident, _)| quote! {
+                #enum_name::#variant_ident => <#field_ty as StrunemixDynValue>::from_dyn(value).map(#enum_data::#variant_ident)
+            });
+
+        let field_into_dyns = fields.iter()
+            .map(|(_, _, variant_ident, _)| quote! {
+                #enum_data::#variant_ident(value) => StrunemixDynValue::into_dyn(value)
+            });
+
+        let dyn_bounds = fields.iter()
+            .map(|(_, field_ty, _, _)| quote! { #field_ty: StrunemixDynValue });
+
+        let where_clause = extend_where(where_clause, generics, dyn_bounds);
+
+        quote! {
+            impl #impl_generics StrunemixDynData<#enum_name> for #enum_data #ty_generics
+                #where_clause
+            {
+                fn dyn_kind(name: &#enum_name) -> DynKind {
+                    match *name {
+                        #(#field_kinds),*
+                    }
+                }
+
+                fn from_dyn(name: &#enum_name, value: &DynValue) -> Option<Self> {
+                    match *name {
+                        #(#field_from_dyns),*
+                    }
+                }
+
+                fn into_dyn(self) -> DynValue {
+                    match self {
+                        #(#field_into_dyns),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let reflect = if have_reflect {
+        let ty_str = ty.to_string();
+        let field_names = fields.iter()
+            .map(|(field_ident, _, _, _)| field_ident.to_string());
+
+        let field_refs = fields.iter()
+            .map(|(field_ident, _, variant_ident, _)| quote! {
+                #enum_name::#variant_ident => Some(&self.#field_ident)
+            })
+            .collect::<Vec<_>>();
+
+        let field_sets = fields.iter()
+            .map(|(field_ident, _, variant_ident, _)| quote! {
+                #enum_data::#variant_ident(value) => self.#field_ident = value
+            });
+
+        let reflect_bounds = fields.iter()
+            .map(|(_, field_ty, _, _)| quote! { #field_ty: ::core::any::Any + ::core::fmt::Debug })
+            .chain(std::iter::once(quote! { #enum_name: for<'b> StrunemixParsableData<'b, #enum_data #ty_generics> }));
+
+        let where_clause = extend_where(where_clause, generics, reflect_bounds);
+
+        quote! {
+            impl #impl_generics StrunemixDyn for #ty #ty_generics
+                #where_clause
+            {
+                fn type_name(&self) -> &'static str {
+                    #ty_str
+                }
+
+                fn field_names(&self) -> &'static [&'static str] {
+                    &[#(#field_names),*]
+                }
+
+                fn get_field_any(&self, name: &str) -> Option<&dyn ::core::any::Any> {
+                    match name.parse::<#enum_name>().ok()? {
+                        #(#field_refs),*
+                    }
+                }
+
+                fn field_debug(&self, name: &str) -> Option<&dyn ::core::fmt::Debug> {
+                    match name.parse::<#enum_name>().ok()? {
+                        #(#field_refs),*
+                    }
+                }
+
+                fn set_field_str(&mut self, name: &str, data: &str) -> Result<(), StrunemixError> {
+                    let name = name.parse::<#enum_name>()?;
+                    match __private::parse_field(&name, data)? {
+                        #(#field_sets),*
+                    }
+                    Ok(())
+                }
+
+                fn as_any(&self) -> &dyn ::core::any::Any {
+                    self
+                }
+
+                fn into_any(self: __private::Box<Self>) -> __private::Box<dyn ::core::any::Any> {
+                    self
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let info = if have_info {
+        let derive_info = get_enum_derive(&ast.attrs, &["strunemix_info"], quote! {});
+
+        let field_info_variants = fields.iter()
+            .map(|(_, _, variant_ident, options)| {
+                let info_ty = options.info.clone().unwrap_or_else(|| parse_quote! { () });
+                quote! { #variant_ident(#info_ty) }
+            });
+
+        let field_info_defaults = fields.iter()
+            .map(|(_, _, variant_ident, _)| quote! {
+                #enum_name::#variant_ident => #enum_info::#variant_ident(::core::default::Default::default())
+            });
+
+        let field_info_names = fields.iter()
+            .map(|(_, _, variant_ident, _)| quote! {
+                #enum_info::#variant_ident(_) => #enum_name::#variant_ident
+            });
+
+        quote! {
+            #derive_info
+            #vis enum #enum_info {
+                #(#field_info_variants),*
+            }
+
+            impl StrunemixInfo<#enum_name> for #enum_info {
+                fn default_for(name: &#enum_name) -> Self {
+                    match *name {
+                        #(#field_info_defaults),*
+                    }
+                }
+            }
+
+            impl From<&#enum_info> for #enum_name {
+                fn from(info: &#enum_info) -> Self {
+                    match info {
+                        #(#field_info_names),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let register = if have_register {
+        if !generics.params.is_empty() {
+            return syn::Error::new_spanned(generics, "Only structs without generics can be registered")
+                .to_compile_error().into();
+        }
+
+        let ty_str = ty.to_string();
+        let static_ident = Ident::new(&format!("__STRUNEMIX_REGISTER_{}", ty_str.to_shouty_snake_case()), Span::call_site());
+
+        quote! {
+            #[__private::linkme::distributed_slice(STRUNEMIX_REGISTRY)]
+            #[linkme(crate = __private::linkme)]
+            #[allow(non_upper_case_globals)]
+            static #static_ident: StrunemixRegistration = StrunemixRegistration {
+                type_name: #ty_str,
+                type_path: ::core::concat!(::core::module_path!(), "::", #ty_str),
+                build: |pairs| {
+                    let mut form = #ty::empty_form::<()>();
+                    for (name, data) in pairs {
+                        form.set_data_str(*name, data)?;
+                    }
+                    Ok(__private::Box::new(#ty::from_form(form)?))
+                },
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    let parsable = if have_parse {
+        let parse_lifetime: syn::Lifetime = parse_quote! { 'strunemix_parse_lifetime__ };
+        let mut leaves = Vec::new();
+        let mut field_from_strs = Vec::new();
+
+        for (field_ident, field_ty, variant_ident, options) in fields.iter() {
+            let mut context = ParseContext::default();
+            let parse = parse_expr(field_ty, options, &mut context);
+            leaves.extend(context.leaves);
+
+            field_from_strs.push(match (options.transforms.is_empty(), context.borrows) {
+                (_, false) => quote! {
+                    #enum_name::#variant_ident => {
+                        let data: &str = &data;
+                        Ok(#enum_data::#variant_ident(#parse))
+                    }
+                },
+                (true, true) => quote! {
+                    #enum_name::#variant_ident => {
+                        let data: &#parse_lifetime str = match data {
+                            __private::Cow::Borrowed(data) => data,
+                            __private::Cow::Owned(_) => return Err(StrunemixParseError::other("The data can't borrow a normalized string")),
+                        };
+                        Ok(#enum_data::#variant_ident(#parse))
+                    }
+                },
+                (false, true) => return syn::Error::new_spanned(field_ident, "Transforms can't be used on a field borrowing the parsed string")
+                    .to_compile_error().into(),
+            });
+        }
+
+        let mut parse_generics = generics.clone();
+        parse_generics.params.insert(0, GenericParam::Lifetime(syn::LifetimeParam::new(parse_lifetime.clone())));
+        let parse_where = parse_generics.make_where_clause();
+        for lifetime in generics.lifetimes() {
+            let lifetime = &lifetime.lifetime;
+            parse_where.predicates.push(parse_quote! { #parse_lifetime: #lifetime });
+        }
+        if !generics.params.is_empty() {
+            for leaf in leaves {
+                parse_where.predicates.push(parse_quote! { #leaf: ::core::str::FromStr });
+                parse_where.predicates.push(parse_quote! { StrunemixParseError: From<<#leaf as ::core::str::FromStr>::Err> });
+            }
+        }
+        let (parse_impl_generics, _, parse_where_clause) = parse_generics.split_for_impl();
+
+        quote! {
+            impl #parse_impl_generics StrunemixParsableData<#parse_lifetime, #enum_data #ty_generics> for #enum_name
+                #parse_where_clause
+            {
+                fn add_data(&self, data: __private::Cow<#parse_lifetime, str>) -> Result<#enum_data #ty_generics, StrunemixParseError> {
+                    match self {
+                        #(#field_from_strs),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let normalize = if fields.iter().any(|(_, _, _, options)| !options.transforms.is_empty()) {
+        let field_normalizes = fields.iter()
+            .map(|(_, _, variant_ident, options)| {
+                let transforms = &options.transforms;
+                quote! {
+                    #enum_name::#variant_ident => {
+                        let data = __private::Cow::Borrowed(data);
+                        #(#transforms)*
+                        data
+                    }
+                }
+            });
+
+        quote! {
+            fn normalize<'a>(&self, data: &'a str) -> __private::Cow<'a, str> {
+                match *self {
+                    #(#field_normalizes),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let check = if fields.iter().any(|(_, _, _, options)| !options.constraints.is_empty()) {
+        let field_checks = fields.iter()
+            .map(|(field_ident, field_ty, variant_ident, options)| {
+                if options.constraints.is_empty() {
+                    return quote! { #enum_data::#variant_ident(_) => Ok(()) };
+                }
+
+                let field_name = field_ident.to_string();
+                let unwrap_option = generic_argument(field_ty, "Option").map(|_| quote! {
+                    let Some(value) = value else { return Ok(()) };
+                });
+                let checks = options.constraints.iter()
+                    .map(|Constraint { condition, description }| quote! {
+                        if !(#condition) {
+                            return Err(StrunemixConstraintError { field: #field_name, constraint: #description });
+                        }
+                    });
+
+                quote! {
+                    #enum_data::#variant_ident(value) => {
+                        #unwrap_option
+                        #(#checks)*
+                        Ok(())
+                    }
+                }
+            });
+
+        quote! {
+            #[allow(unused_comparisons)]
+            fn check(&self) -> Result<(), StrunemixConstraintError> {
+                match self {
+                    #(#field_checks),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let default_data = if fields.iter().any(|(_, _, _, options)| options.default.is_some()) {
+        let field_defaults = fields.iter()
+            .map(|(_, _, variant_ident, options)| match &options.default {
+                Some(default) => quote! { #enum_name::#variant_ident => Some(#enum_data::#variant_ident(#default)) },
+                None => quote! { #enum_name::#variant_ident => None },
+            });
+
+        quote! {
+            fn default_data(name: &#enum_name) -> Option<Self> {
+                match *name {
+                    #(#field_defaults),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let field_type_names = fields.iter()
+        .map(|(_, field_ty, variant_ident, _)| {
+            let type_name = type_name(field_ty);
+            quote! { #enum_name::#variant_ident => #type_name }
+        });
+
+    let description = if fields.iter().any(|(_, _, _, options)| options.description.is_some()) {
+        let field_descriptions = fields.iter()
+            .map(|(_, _, variant_ident, options)| match &options.description {
+                Some(description) => quote! { #enum_name::#variant_ident => Some(#description) },
+                None => quote! { #enum_name::#variant_ident => None },
+            });
+
+        quote! {
+            fn description(&self) -> Option<&'static str> {
+                match *self {
+                    #(#field_descriptions),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let label = if fields.iter().any(|(_, _, _, options)| options.label.is_some()) {
+        let field_labels = fields.iter()
+            .map(|(field_ident, _, variant_ident, options)| match &options.label {
+                Some(label) => quote! { #enum_name::#variant_ident => #label },
+                None => {
+                    let field_name = field_ident.to_string();
+                    quote! { #enum_name::#variant_ident => #field_name }
+                },
+            });
+
+        quote! {
+            fn label(&self) -> &'static str {
+                match *self {
+                    #(#field_labels),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let placeholder = if fields.iter().any(|(_, _, _, options)| options.placeholder.is_some()) {
+        let field_placeholders = fields.iter()
+            .map(|(_, _, variant_ident, options)| match &options.placeholder {
+                Some(placeholder) => quote! { #enum_name::#variant_ident => Some(#placeholder) },
+                None => quote! { #enum_name::#variant_ident => None },
+            });
+
+        quote! {
+            fn placeholder(&self) -> Option<&'static str> {
+                match *self {
+                    #(#field_placeholders),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let meta = if fields.iter().any(|(_, _, _, options)| !options.meta.is_empty()) {
+        let field_metas = fields.iter()
+            .map(|(_, _, variant_ident, options)| {
+                let keys = options.meta.iter().map(|(key, _)| key);
+                let values = options.meta.iter().map(|(_, value)| value);
+                quote! {
+                    #enum_name::#variant_ident => match key {
+                        #(#keys => Some(#values),)*
+                        _ => None,
+                    }
+                }
+            });
+
+        quote! {
+            fn meta(&self, key: &str) -> Option<&'static str> {
+                match *self {
+                    #(#field_metas),*
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let snapshot = if derive_type_clone && generics.params.is_empty() {
+        quote! {
+            fn snapshot(&self) -> Option<Self> {
+                Some(::core::clone::Clone::clone(self))
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let enum_name_str = enum_name.to_string();
     let tokens = quote! {
 
@@ -284,16 +833,51 @@ pub fn field_type(input: TokenStream) -> TokenStream {
                     #(#field_name_to_strs),*
                 }
             }
+
+            fn from_index(index: usize) -> Option<Self> {
+                match index {
+                    #(#field_name_by_indexes,)*
+                    _ => None,
+                }
+            }
+
+            fn index(&self) -> usize {
+                match *self {
+                    #(#field_name_to_indexes),*
+                }
+            }
+
+            fn type_name(&self) -> &'static str {
+                match *self {
+                    #(#field_type_names),*
+                }
+            }
+
+            #normalize
+
+            #description
+
+            #label
+
+            #placeholder
+
+            #meta
         }
 
-        impl #impl_generics StrunemixData<#enum_name> for #enum_data #ty_generics #where_clause {}
+        impl #impl_generics StrunemixData<#enum_name> for #enum_data #ty_generics #where_clause {
+            #check
+
+            #default_data
 
-        impl std::str::FromStr for #enum_name {
+            #snapshot
+        }
+
+        impl ::core::str::FromStr for #enum_name {
             type Err = StrunemixFromError;
             fn from_str(name: &str) -> Result<Self, Self::Err> {
                 match name {
                     #(#field_name_by_strs),*,
-                    _ => Err(StrunemixFromError::NotAnEnumName(name.to_string(), #enum_name_str.to_string())),
+                    _ => StrunemixFromError::find_name(name, #enum_name_str, StrunemixNameMatching::#name_matching),
                 }
             }
         }
@@ -315,6 +899,18 @@ pub fn field_type(input: TokenStream) -> TokenStream {
 
         #tryfromarray
 
+        #displayable
+
+        #dynamic
+
+        #reflect
+
+        #register
+
+        #info
+
+        #parsable
+
         #converter
 
         #fields_count_impl
@@ -331,12 +927,75 @@ pub fn field_type(input: TokenStream) -> TokenStream {
             fn as_name_array() -> [#enum_name; #fields_count] {
                 [#(#field_name_constructs),*]
             }
+
+            #from_data_slots
         }
 
     };
     tokens.into()
 }
 
+/// Get how the names of the fields are matched, from the `#[strunemix_names(...)]` attribute
+fn get_name_matching(attrs: &[Attribute]) -> syn::Result<Ident> {
+    let mut name_matching = Ident::new("Exact", Span::call_site());
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("strunemix_names")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("case_insensitive") {
+                name_matching = Ident::new("CaseInsensitive", Span::call_site());
+                return Ok(());
+            }
+
+            if meta.path.is_ident("normalized") {
+                name_matching = Ident::new("Normalized", Span::call_site());
+                return Ok(());
+            }
+
+            Err(meta.error("Unknown attribute value, only `case_insensitive` and `normalized` allowed."))
+        })?;
+    }
+
+    Ok(name_matching)
+}
+
+/// Check that no two fields match the same strings with the chosen names lookup
+fn check_name_collisions(fields: &[(Ident, Type, Ident, FieldOptions)], name_matching: &Ident) -> syn::Result<()> {
+    let key = |field_ident: &Ident| -> String {
+        let name = field_ident.to_string();
+        match name_matching.to_string().as_str() {
+            "CaseInsensitive" => name.to_lowercase(),
+            "Normalized" => name.chars().filter(|c| *c != '_' && *c != '-').flat_map(char::to_lowercase).collect(),
+            _ => name,
+        }
+    };
+
+    for (index, (field_ident, _, _, _)) in fields.iter().enumerate() {
+        if let Some((other_ident, _, _, _)) = fields[..index].iter().find(|(other_ident, _, _, _)| key(other_ident) == key(field_ident)) {
+            return Err(syn::Error::new_spanned(field_ident, format!("The field `{field_ident}` matches the same names as the field `{other_ident}` with the names lookup of `#[strunemix_names(...)]`")));
+        }
+    }
+
+    Ok(())
+}
+
+/// Add bounds to the where clause of a generic struct, there is no need for them otherwise.
+fn extend_where(where_clause: Option<&WhereClause>, generics: &Generics, bounds: impl Iterator<Item = TokenStream2>) -> TokenStream2 {
+    if generics.params.is_empty() {
+        return quote! {};
+    }
+
+    let predicates = where_clause.into_iter().flat_map(|where_clause| where_clause.predicates.iter());
+    quote! { where #(#predicates,)* #(#bounds),* }
+}
+
+/// Check if the first of the derive attributes found derives the trait `name`
+fn enum_derives(attrs: &[Attribute], derive_attr_names: &[&str], name: &str) -> bool {
+    attrs.iter()
+    .find(|attr| derive_attr_names.iter().any(|attr_name| attr.path().is_ident(attr_name)))
+    .and_then(|attr| attr.parse_args_with(Punctuated::<Path, syn::Token![,]>::parse_terminated).ok())
+    .is_some_and(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == name)))
+}
+
 fn get_enum_derive(attrs: &[Attribute], derive_attr_names: &[&str], default: TokenStream2) -> TokenStream2 {
 
     attrs.iter()
@@ -369,38 +1028,308 @@ fn get_enum_derive(attrs: &[Attribute], derive_attr_names: &[&str], default: Tok
 
 }
 
-fn filter_fields(fields: &Fields) -> Vec<(Ident, Type, Ident)> {
-    fields.iter()
-        .filter_map(|field| {
-            if field.attrs.iter()
-                .find(|attr| has_skip_attr(attr))
-                .is_none() && field.ident.is_some()
-            {
-                let field_ty = field.ty.clone();
-                let field_ident = field.ident.as_ref().unwrap().clone();
-                let field_name = field.ident.as_ref().unwrap().to_string();
-                let variant_ident = Ident::new(&field_name.to_upper_camel_case(), Span::call_site());
-                Some((field_ident, field_ty, variant_ident))
-            } else {
-                None
+fn filter_fields(fields: &Fields) -> syn::Result<Vec<(Ident, Type, Ident, FieldOptions)>> {
+    let mut filtered = Vec::new();
+
+    for field in fields.iter() {
+        let options = FieldOptions::from_attrs(&field.attrs)?;
+
+        if let (Some(field_ident), false) = (&field.ident, options.skip) {
+            let field_ty = field.ty.clone();
+            let field_name = field_ident.to_string();
+            let variant_ident = Ident::new(&field_name.to_upper_camel_case(), Span::call_site());
+            filtered.push((field_ident.clone(), field_ty, variant_ident, options));
+        }
+    }
+
+    Ok(filtered)
+}
+
+/// The type of a field as written in the struct, without the spaces added between its tokens
+fn type_name(ty: &Type) -> String {
+    let mut type_name = ty.to_token_stream().to_string();
+
+    for (spaced, joined) in [(" :: ", "::"), (":: ", "::"), (" < ", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), ("& ", "&"), ("( ", "("), (" )", ")"), ("[ ", "["), (" ]", "]"), (" ;", ";")] {
+        type_name = type_name.replace(spaced, joined);
+    }
+
+    type_name
+}
+
+/// Options set on a field with `#[strunemix(...)]`
+#[derive(Default)]
+struct FieldOptions {
+    skip: bool,
+    delimiter: Option<LitStr>,
+    separator: Option<LitStr>,
+    transforms: Vec<TokenStream2>,
+    constraints: Vec<Constraint>,
+    default: Option<TokenStream2>,
+    info: Option<Type>,
+    description: Option<String>,
+    label: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    meta: Vec<(String, LitStr)>,
+}
+
+/// A constraint declared on a field, with the condition the value satisfies
+/// and the `StrunemixConstraint` describing it
+struct Constraint {
+    condition: TokenStream2,
+    description: TokenStream2,
+}
+
+impl Constraint {
+    fn bounds(meta: &syn::meta::ParseNestedMeta, value: TokenStream2, kind: &str) -> syn::Result<Self> {
+        let (mut min, mut max) = (None, None);
+
+        meta.parse_nested_meta(|bound| {
+            if bound.path.is_ident("min") {
+                min = Some(bound.value()?.parse::<syn::Expr>()?);
+                return Ok(());
             }
+
+            if bound.path.is_ident("max") {
+                max = Some(bound.value()?.parse::<syn::Expr>()?);
+                return Ok(());
+            }
+
+            Err(bound.error("Unknown bound, only `min` and `max` allowed."))
+        })?;
+
+        let conditions = min.iter().map(|min| quote! { #min <= #value })
+            .chain(max.iter().map(|max| quote! { #value <= #max }))
+            .collect::<Vec<_>>();
+
+        if conditions.is_empty() {
+            return Err(meta.error("At least one of `min` and `max` has to be set."));
+        }
+
+        let describe = |bound: Option<syn::Expr>| match bound {
+            Some(bound) => {
+                let bound = bound.to_token_stream().to_string().replace(' ', "");
+                quote! { Some(#bound) }
+            },
+            None => quote! { None },
+        };
+        let (min, max) = (describe(min), describe(max));
+        let kind = Ident::new(kind, Span::call_site());
+
+        Ok(Constraint {
+            condition: quote! { #(#conditions)&&* },
+            description: quote! { StrunemixConstraint::#kind { min: #min, max: #max } },
         })
-        .collect::<Vec<_>>()
+    }
 }
 
-fn has_skip_attr(attr: &Attribute) -> bool {
+impl FieldOptions {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = FieldOptions::default();
+
+        let doc_lines = attrs.iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta {
+                Meta::NameValue(syn::MetaNameValue { value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(line), .. }), .. }) => Some(line.value()),
+                _ => None,
+            })
+            .map(|line| line.strip_prefix(' ').map(String::from).unwrap_or(line))
+            .collect::<Vec<_>>();
+        let description = doc_lines.join("\n").trim().to_string();
+        options.description = (!description.is_empty()).then_some(description);
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("strunemix")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    options.skip = true;
+                    return Ok(());
+                }
 
-    if !attr.path().is_ident("strunemix"){
-        return false;
+                if meta.path.is_ident("delimiter") {
+                    options.delimiter = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("separator") {
+                    options.separator = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                for transform in ["trim", "lowercase", "uppercase", "collapse_whitespace"] {
+                    if meta.path.is_ident(transform) {
+                        let transform = Ident::new(transform, Span::call_site());
+                        options.transforms.push(quote! { let data = StrunemixTransform::#transform(data); });
+                        return Ok(());
+                    }
+                }
+
+                if meta.path.is_ident("range") {
+                    options.constraints.push(Constraint::bounds(&meta, quote! { *value }, "Range")?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("length") {
+                    options.constraints.push(Constraint::bounds(&meta, quote! { StrunemixLength::length(value) }, "Length")?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("pattern") {
+                    let pattern: LitStr = meta.value()?.parse()?;
+                    if let Err(error) = regex_syntax::Parser::new().parse(&pattern.value()) {
+                        return Err(syn::Error::new(pattern.span(), format!("Invalid pattern: {error}")));
+                    }
+                    options.constraints.push(Constraint {
+                        condition: quote! { __private::pattern!(#pattern, value) },
+                        description: quote! { StrunemixConstraint::Pattern(#pattern) },
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("one_of") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let values = Punctuated::<syn::Lit, syn::Token![,]>::parse_terminated(&content)?;
+                    let values_strs = values.iter().map(|value| value.to_token_stream().to_string());
+                    let values = values.iter();
+                    options.constraints.push(Constraint {
+                        condition: quote! { #(*value == #values)||* },
+                        description: quote! { StrunemixConstraint::OneOf(&[#(#values_strs),*]) },
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("default") {
+                    options.default = Some(match meta.input.peek(syn::Token![=]) {
+                        true => {
+                            let default: Path = meta.value()?.parse()?;
+                            quote! { #default() }
+                        },
+                        false => quote! { ::core::default::Default::default() },
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("info") {
+                    options.info = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("label") {
+                    options.label = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("placeholder") {
+                    options.placeholder = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("meta") {
+                    return meta.parse_nested_meta(|entry| {
+                        let key = entry.path.get_ident()
+                            .ok_or_else(|| entry.error("The key of a metadata has to be an identifier."))?
+                            .to_string();
+                        if options.meta.iter().any(|(other, _)| *other == key) {
+                            return Err(entry.error(format!("The metadata key `{key}` is set more than once.")));
+                        }
+                        options.meta.push((key, entry.value()?.parse()?));
+                        Ok(())
+                    });
+                }
+
+                if meta.path.is_ident("transform") {
+                    let transform: Path = meta.value()?.parse()?;
+                    options.transforms.push(quote! { let data = StrunemixTransform::apply(data, #transform); });
+                    return Ok(());
+                }
+
+                Err(meta.error("Unknown attribute value, only `skip`, `default`, `delimiter`, `separator`, `trim`, `lowercase`, `uppercase`, `collapse_whitespace`, `transform`, `range`, `length`, `pattern`, `one_of`, `info`, `label`, `placeholder` and `meta` allowed."))
+            })?;
+        }
+
+        Ok(options)
     }
+}
 
-    attr.parse_nested_meta(|meta| {
-        if meta.path.is_ident("skip") {
-            return Ok(());
+/// What the parsing expressions built by [parse_expr] rely on
+#[derive(Default)]
+struct ParseContext {
+    /// Types parsed with their `FromStr` implementation
+    leaves: Vec<Type>,
+    /// Whether the parsed value borrows the input string slice
+    borrows: bool,
+}
+
+/// Build the expression parsing the string slice `data` into a value of type `ty`, inside a function returning a `StrunemixParseError`.
+/// `Option`, `Vec`, tuples and `()` are handled here, other types are parsed with their `FromStr` implementation.
+fn parse_expr(ty: &Type, options: &FieldOptions, context: &mut ParseContext) -> TokenStream2 {
+    match ty {
+        Type::Paren(inner) => parse_expr(&inner.elem, options, context),
+        Type::Group(inner) => parse_expr(&inner.elem, options, context),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => quote! { () },
+        Type::Tuple(tuple) => {
+            let separator = options.separator.clone().unwrap_or_else(|| LitStr::new(",", Span::call_site()));
+            let count = tuple.elems.len();
+            let elems = tuple.elems.iter().map(|elem| {
+                let elem = parse_expr(elem, options, context);
+                quote! {{
+                    let data: &str = parts.next().unwrap_or("");
+                    #elem
+                }}
+            }).collect::<Vec<_>>();
+
+            quote! {{
+                let mut parts = data.splitn(#count, #separator);
+                (#(#elems,)*)
+            }}
+        },
+        Type::Reference(reference) if is_path(&reference.elem, "str") => {
+            context.borrows = true;
+            quote! { data }
+        },
+        Type::Path(_) if is_path(ty, "String") => quote! { __private::String::from(data) },
+        Type::Path(_) if generic_argument(ty, "Option").is_some() => {
+            let inner = parse_expr(generic_argument(ty, "Option").unwrap_or(ty), options, context);
+            quote! {
+                if data.is_empty() || data == "null" { None } else { Some(#inner) }
+            }
+        },
+        Type::Path(_) if generic_argument(ty, "Vec").is_some() => {
+            let delimiter = options.delimiter.clone().unwrap_or_else(|| LitStr::new(",", Span::call_site()));
+            let inner = parse_expr(generic_argument(ty, "Vec").unwrap_or(ty), options, context);
+            quote! {{
+                let mut items = __private::Vec::new();
+                if !data.is_empty() {
+                    for data in data.split(#delimiter) {
+                        items.push(#inner);
+                    }
+                }
+                items
+            }}
+        },
+        _ => {
+            context.leaves.push(ty.clone());
+            quote! { data.parse::<#ty>()? }
         }
+    }
+}
 
-        Err(meta.error("Unknown attribute value, only `skip` allowed."))
-    })
-    .is_ok()
+/// Check if the type is a path ending with `name`, without generic arguments
+fn is_path(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.segments.last()
+            .is_some_and(|segment| segment.ident == name && segment.arguments.is_empty()),
+        _ => false
+    }
+}
 
+/// Get the only generic type argument of a type if it is a path ending with `name`
+fn generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last().filter(|segment| segment.ident == name)?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None };
+
+    match arguments.args.iter().collect::<Vec<_>>()[..] {
+        [GenericArgument::Type(inner)] => Some(inner),
+        _ => None
+    }
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+//! Checks that strunemix and the code derived by it build in a `no_std` crate, with only `alloc`.
+//! 
+//! Build it on its own so that the `std` feature of strunemix is not enabled by the other crates of the workspace:
+//! 
+//! ```sh
+//! cargo build -p strunemix_no_std
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use strunemix::*;
+
+/// A telemetry frame, using most of the field attributes.
+#[derive(Debug, PartialEq, Strunemix)]
+#[strunemix_derive_data(Debug, PartialEq, Clone)]
+#[strunemix_parse]
+pub struct Frame {
+    #[strunemix(trim, lowercase, length(min = 1, max = 16))]
+    pub sensor: String,
+    #[strunemix(range(min = -40, max = 85))]
+    pub temperature: i16,
+    #[strunemix(one_of("ok", "degraded"))]
+    pub status: String,
+    #[strunemix(default, delimiter = ";")]
+    pub samples: Vec<u16>,
+    pub battery: Option<u8>,
+}
+
+/// Parse a frame from logfmt.
+pub fn parse_frame(line: &str) -> Result<Frame, StrunemixError> {
+    let mut form = Frame::empty_form::<()>();
+    form.set_logfmt(line)?;
+    form.enable_history(4);
+
+    Frame::from_form_or_default(form).map_err(|error| error.error)
+}
+
+/// Change the temperature of a frame from a string.
+pub fn set_temperature(frame: Frame, temperature: &str) -> Result<Frame, StrunemixError> {
+    let mut form = frame.to_form::<()>();
+    form.set_data_str(FrameAttrName::Temperature, temperature)?;
+
+    Frame::from_form(form)
+}
@@ -0,0 +1,14 @@
+use strunemix::*;
+use strunemix_no_std::*;
+
+#[test]
+fn roundtrip() -> Result<(), StrunemixError> {
+    let frame = parse_frame(r#"sensor=" Boiler " temperature=62 status=ok battery="#)?;
+
+    assert_eq!(frame, Frame {sensor: "boiler".to_string(), temperature: 62, status: "ok".to_string(), samples: vec![], battery: None});
+    assert_eq!(set_temperature(frame, "63")?.temperature, 63);
+
+    assert!(matches!(parse_frame("sensor=boiler temperature=120 status=ok battery=1"), Err(StrunemixError::Constraint(_))));
+
+    Ok(())
+}